@@ -21,7 +21,7 @@ impl<'a> Widget for ChannelSelector<'a> {
     fn ui(self, ui: &mut egui::Ui) -> egui::Response {
         ui.add(Slider::new(
             self.selected_channel,
-            1..=self.num_channels as u8,
+            1..=self.num_channels,
         ))
     }
 }
@@ -1,14 +1,10 @@
 use egui::{Color32, Pos2, Rect, Rounding, Widget};
-use music_notes::{ChromaticNote, ChromaticTone};
+use music_notes::{ChromaticNote, ChromaticTone, NoteRange};
 
 /// Configuration parameters for customizing the piano widget.
 pub struct PianoConfig {
-    /// Most left key on the keyboard to draw.
-    pub first_key: ChromaticNote,
-    /// Most right key on the keyboard to draw.
-    ///
-    /// Must be equal to or a higher note than `first_key`.
-    pub last_key: ChromaticNote,
+    /// Keys on the keyboard to draw, from the most left to the most right key.
+    pub keys: NoteRange,
 
     /// Ratio between the width and the height of a white key.
     ///
@@ -134,8 +130,7 @@ impl KeyOffset for BlackKeysOffset {
 impl Default for PianoConfig {
     fn default() -> Self {
         Self {
-            first_key: ChromaticNote::new(ChromaticTone::A, 0),
-            last_key: ChromaticNote::new(ChromaticTone::C, 8),
+            keys: NoteRange::PIANO,
             white_key_size_ratio: 6.0,
             black_key_size_ratio: 3.5,
             ratio_width_white_to_black_keys: 0.8,
@@ -234,8 +229,7 @@ impl BlackKeys {
     fn groups_from(white_keys: &[(usize, ChromaticNote)]) -> Vec<BlackKeys> {
         let mut groups = Vec::new();
         let mut current_group = BlackKeys::default();
-        for i in 0..white_keys.len() {
-            let item = &white_keys[i];
+        for item in white_keys {
             if item.1.is_followed_by_black_key() {
                 // Add item to current group. Set starting key when the group doesn't have any keys yet.
                 if current_group.is_empty() {
@@ -266,9 +260,10 @@ impl BlackKeys {
 
 impl Widget for PianoKeys {
     fn ui(self, ui: &mut egui::Ui) -> egui::Response {
-        let white_keys: Vec<(usize, ChromaticNote)> = (i32::from(self.config.first_key)
-            ..=i32::from(self.config.last_key))
-            .map(|i| ChromaticNote::from(i))
+        let white_keys: Vec<(usize, ChromaticNote)> = self
+            .config
+            .keys
+            .iter()
             .filter(|n| n.is_white_key())
             .enumerate()
             .collect();
@@ -288,7 +283,7 @@ impl Widget for PianoKeys {
 
         // Draw white keys.
         white_keys.iter().for_each(|(key_number, note)| {
-            let fill_color = if self.pressed_keys.contains(&note) {
+            let fill_color = if self.pressed_keys.contains(note) {
                 self.config.color_white_pressed_key
             } else {
                 self.config.color_white_key
@@ -324,7 +319,7 @@ impl Widget for PianoKeys {
                 .enumerate()
                 .for_each(|(key_in_group_index, black_key)| {
                     let next_white_index = group.starting_index + key_in_group_index;
-                    let fill_color = if self.pressed_keys.contains(&black_key) {
+                    let fill_color = if self.pressed_keys.contains(black_key) {
                         self.config.color_black_pressed_key
                     } else {
                         self.config.color_black_key
@@ -45,13 +45,34 @@ impl eframe::App for Gui {
                 ));
             });
 
-            let channel = &device_state[self.selected_channel];
-            let pressed_keys = channel
-                .active_notes
-                .iter()
-                .map(|note_id| device_state.note_by_note_index(*note_id))
-                .map(|note_state| note_state.note)
-                .collect();
+            let pressed_keys = device_state.active_notes(self.selected_channel);
+            let chord = device_state
+                .chord(self.selected_channel)
+                .map(|chord| chord.to_string())
+                .unwrap_or_default();
+            let key = APP_MODEL
+                .key
+                .read()
+                .key()
+                .map(|detected| format!("{} ({:.2})", detected.key, detected.confidence))
+                .unwrap_or_default();
+            ui.horizontal(|ui| {
+                ui.label("Chord: ");
+                ui.label(chord);
+            });
+            let pitch_class_set = device_state.pitch_class_set(self.selected_channel);
+            let set_class = match pitch_class_set.forte_number() {
+                Some(forte_number) => format!("{forte_number} {}", pitch_class_set.prime_form()),
+                None => pitch_class_set.prime_form().to_string(),
+            };
+            ui.horizontal(|ui| {
+                ui.label("Set class: ");
+                ui.label(set_class);
+            });
+            ui.horizontal(|ui| {
+                ui.label("Key: ");
+                ui.label(key);
+            });
             ui.add(PianoKeys::new(PianoConfig::default(), pressed_keys));
         });
     }
@@ -29,8 +29,9 @@ impl Module for MidiLogger {
 
     fn handle_midi_in(&self, _port_identifier: &PortIdentifier, midi_message: &Message) {
         APP_MODEL.channels.write().apply_event(&midi_message.event);
+        APP_MODEL.key.write().apply_event(&midi_message.event);
         refresh();
-        println!("{:?}", midi_message.event);
+        println!("{}", midi_message.event);
     }
 
     fn handle_midi_out(
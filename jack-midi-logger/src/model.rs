@@ -1,14 +1,16 @@
 use egui::mutex::RwLock;
-use midi_device::DeviceState;
+use midi_device::{DeviceState, KeyTracker};
 
 pub struct Model {
     pub channels: RwLock<DeviceState>,
+    pub key: RwLock<KeyTracker>,
 }
 
 impl Default for Model {
     fn default() -> Self {
         Model {
             channels: RwLock::new(DeviceState::new(16, 128)),
+            key: RwLock::new(KeyTracker::default()),
         }
     }
 }
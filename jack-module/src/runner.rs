@@ -1,7 +1,7 @@
 use std::io::stdin;
 
 use jack::{Client, MidiIn, MidiOut, Port, ProcessHandler};
-use midi_events::Message;
+use midi_events::{Event, Message, SystemExclusiveAssembler, Ticks};
 
 use crate::{Module, PortType};
 
@@ -23,7 +23,7 @@ where
     I: Copy,
 {
     module: T,
-    midi_in_ports: Vec<(I, Port<MidiIn>)>,
+    midi_in_ports: Vec<(I, Port<MidiIn>, SystemExclusiveAssembler)>,
     midi_out_ports: Vec<(I, Port<MidiOut>)>,
 }
 
@@ -33,7 +33,7 @@ where
     T: Module<PortDescriptorIdentifierType = I>,
 {
     fn new(jack_client: &Client, module: T) -> Self {
-        let midi_in_ports: Vec<(I, Port<MidiIn>)> = module
+        let midi_in_ports: Vec<(I, Port<MidiIn>, SystemExclusiveAssembler)> = module
             .port_descriptors()
             .iter()
             .filter(|pd| pd.is_input() && pd.port_type() == PortType::Midi)
@@ -41,7 +41,7 @@ where
                 let port = jack_client
                     .register_port(pd.name(), MidiIn::default())
                     .unwrap();
-                (pd.identifier(), port)
+                (pd.identifier(), port, SystemExclusiveAssembler::default())
             })
             .collect();
 
@@ -71,11 +71,24 @@ where
     I: Copy + Send,
 {
     fn process(&mut self, _: &Client, process_scope: &jack::ProcessScope) -> jack::Control {
-        for (port_identifier, port) in &self.midi_in_ports {
+        for (port_identifier, port, system_exclusive) in &mut self.midi_in_ports {
             let midi_events = port.iter(process_scope);
             for raw_event in midi_events {
-                let message = Message::from(&raw_event);
-                self.module.handle_midi_in(port_identifier, &message);
+                // Skip malformed input, a single bad message shouldn't stop the client.
+                if system_exclusive.accepts(raw_event.bytes) {
+                    if let Some(Ok(message)) = system_exclusive.push(raw_event.bytes) {
+                        let message = Message {
+                            delta_time: raw_event.time as Ticks,
+                            event: Event::SystemExclusive(message),
+                        };
+                        self.module.handle_midi_in(port_identifier, &message);
+                    }
+                } else {
+                    system_exclusive.interrupt(raw_event.bytes);
+                    if let Ok(message) = Message::try_from(&raw_event) {
+                        self.module.handle_midi_in(port_identifier, &message);
+                    }
+                }
             }
         }
 
@@ -84,8 +97,10 @@ where
             for midi_event in midi_events {
                 let mut bytes = Vec::new();
 
-                let raw_event = midi_event.encode_into(&mut bytes);
-                port_pair.1.writer(process_scope).write(&raw_event).unwrap();
+                // Skip events that can't be encoded, like notes outside the midi range.
+                if let Ok(raw_event) = midi_event.encode_into(&mut bytes) {
+                    port_pair.1.writer(process_scope).write(&raw_event).unwrap();
+                }
             }
         }
 
@@ -115,7 +130,7 @@ where
     }
 
     fn run(self, module: T) {
-        let runtime = Self::Runtime::new(&self.jack_client, module);
+        let runtime = self.init(module);
         let active_client = self.jack_client.activate_async((), runtime).unwrap();
         println!("Press enter to quit");
         let mut user_input = String::new();
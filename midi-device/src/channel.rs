@@ -1,6 +1,7 @@
 use crate::NoteStateId;
 
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChannelState {
     pub active_notes: Vec<NoteStateId>,
 }
@@ -0,0 +1,72 @@
+use midi_events::{Event, SongPosition, Ticks, CLOCKS_PER_QUARTER};
+
+/// Number of timing clocks per sixteenth note, the unit of the song position pointer.
+const CLOCKS_PER_SIXTEENTH: Ticks = CLOCKS_PER_QUARTER / 4;
+
+/// Tracks the transport of an external midi clock, like a drum machine or sequencer.
+///
+/// # Example
+///
+/// ```
+/// use midi_device::*;
+/// use midi_events::*;
+///
+/// let mut clock = ClockTracker::default();
+/// clock.apply_event(&Event::Start);
+/// for _ in 0..CLOCKS_PER_QUARTER * 2 {
+///     clock.apply_event(&Event::TimingClock);
+/// }
+/// assert!(clock.is_running());
+/// assert_eq!(clock.clocks(), 48);
+/// assert_eq!(clock.song_position(), 8);
+///
+/// // Clocks are ignored while stopped, continue resumes at the song position.
+/// clock.apply_event(&Event::Stop);
+/// clock.apply_event(&Event::TimingClock);
+/// clock.apply_event(&Event::SongPosition(16));
+/// clock.apply_event(&Event::Continue);
+/// clock.apply_event(&Event::TimingClock);
+/// assert_eq!(clock.clocks(), 16 * 6 + 1);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ClockTracker {
+    running: bool,
+    clocks: Ticks,
+}
+
+impl ClockTracker {
+    /// Apply the given midi event. Only transport, song position and timing clock events are
+    /// used.
+    pub fn apply_event(&mut self, event: &Event) {
+        match event {
+            Event::Start => {
+                self.clocks = 0;
+                self.running = true;
+            }
+            Event::Continue => self.running = true,
+            Event::Stop => self.running = false,
+            Event::SongPosition(position) => {
+                self.clocks = *position as Ticks * CLOCKS_PER_SIXTEENTH;
+            }
+            Event::TimingClock if self.running => self.clocks += 1,
+            Event::SystemReset => *self = ClockTracker::default(),
+            _ => {}
+        }
+    }
+
+    /// Is the clock source playing.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Number of timing clocks since the start of the song, at [`CLOCKS_PER_QUARTER`] ticks
+    /// per quarter note.
+    pub fn clocks(&self) -> Ticks {
+        self.clocks
+    }
+
+    /// Number of sixteenth notes since the start of the song.
+    pub fn song_position(&self) -> SongPosition {
+        (self.clocks / CLOCKS_PER_SIXTEENTH).min(0x3fff) as SongPosition
+    }
+}
@@ -1,19 +1,147 @@
-use std::ops::{Index, IndexMut};
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
 
 use midi_events::{Channel, Event, Velocity};
-use music_notes::ChromaticNote;
+use music_notes::{recognize_chord, ChromaticNote, PitchClassSet, RecognizedChord};
 
 use crate::NoteStateId;
 
 use super::{ChannelState, NoteState};
 
+/// State of the notes and channels of a midi device.
+///
+/// With the `serde` feature the state can be stored. Stored state is checked when it is
+/// read, the device must have a channel and every note must either be unused or active on
+/// the channel it refers to.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use midi_device::*;
+/// use midi_events::*;
+/// use music_notes::*;
+///
+/// let device = DeviceState::new(1, 2);
+/// let json = serde_json::to_string(&device).unwrap();
+/// let device: DeviceState = serde_json::from_str(&json).unwrap();
+/// assert_eq!(device.num_unused_notes(), 2);
+///
+/// let error = serde_json::from_str::<DeviceState>(
+///     r#"{"notes":[],"channels":[{"active_notes":[]}],"unused_notes":[5]}"#,
+/// )
+/// .unwrap_err();
+/// assert!(error.to_string().starts_with("note 5 doesn't exist"));
+///
+/// let error = serde_json::from_str::<DeviceState>(
+///     r#"{"notes":[],"channels":[],"unused_notes":[]}"#,
+/// )
+/// .unwrap_err();
+/// assert!(error.to_string().starts_with("device has no channels"));
+///
+/// // Move the active note of the first channel to the second channel.
+/// let mut device = DeviceState::new(2, 1);
+/// device.apply_event(&Event::NoteOn(1, ChromaticNote::new(ChromaticTone::C, 4), 100));
+/// let mut json = serde_json::to_value(&device).unwrap();
+/// json["channels"][0]["active_notes"] = serde_json::json!([]);
+/// json["channels"][1]["active_notes"] = serde_json::json!([0]);
+/// let error = serde_json::from_value::<DeviceState>(json).unwrap_err();
+/// assert!(error.to_string().starts_with("note 0 is active on channel 2"));
+/// # }
+/// ```
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedDeviceState"))]
 pub struct DeviceState {
     notes: Vec<NoteState>,
     channels: Vec<ChannelState>,
     unused_notes: Vec<NoteStateId>,
 }
 
+/// Reason why stored device state can't be used.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DeviceStateError {
+    /// A channel or the unused notes refer to a note that doesn't exist.
+    UnknownNote(NoteStateId),
+    /// The note is active on more than one channel, or both active and unused.
+    DuplicateNote(NoteStateId),
+    /// The note is neither active on a channel nor unused.
+    MissingNote(NoteStateId),
+    /// The note is active on a channel, but refers to another channel.
+    WrongChannel(NoteStateId, usize),
+    /// The device doesn't have any channels.
+    NoChannels,
+}
+
+impl Display for DeviceStateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownNote(note_id) => write!(f, "note {note_id} doesn't exist"),
+            Self::DuplicateNote(note_id) => write!(f, "note {note_id} is used more than once"),
+            Self::MissingNote(note_id) => {
+                write!(f, "note {note_id} is neither active nor unused")
+            }
+            Self::WrongChannel(note_id, channel_id) => write!(
+                f,
+                "note {note_id} is active on channel {channel_id} but refers to another channel"
+            ),
+            Self::NoChannels => write!(f, "device has no channels"),
+        }
+    }
+}
+
+impl Error for DeviceStateError {}
+
+/// Device state as it is stored, before it is checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedDeviceState {
+    notes: Vec<NoteState>,
+    channels: Vec<ChannelState>,
+    unused_notes: Vec<NoteStateId>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedDeviceState> for DeviceState {
+    type Error = DeviceStateError;
+    fn try_from(state: UncheckedDeviceState) -> Result<Self, Self::Error> {
+        if state.channels.is_empty() {
+            return Err(DeviceStateError::NoChannels);
+        }
+        let mut used = vec![false; state.notes.len()];
+        let note_ids = state
+            .channels
+            .iter()
+            .flat_map(|channel| channel.active_notes.iter())
+            .chain(state.unused_notes.iter());
+        for note_id in note_ids {
+            match used.get_mut(*note_id) {
+                None => return Err(DeviceStateError::UnknownNote(*note_id)),
+                Some(true) => return Err(DeviceStateError::DuplicateNote(*note_id)),
+                Some(used) => *used = true,
+            }
+        }
+        if let Some(note_id) = used.iter().position(|used| !used) {
+            return Err(DeviceStateError::MissingNote(note_id));
+        }
+        for (index, channel) in state.channels.iter().enumerate() {
+            let channel_id = index + 1;
+            for note_id in &channel.active_notes {
+                if state.notes[*note_id].channel_id.map(usize::from) != Some(channel_id) {
+                    return Err(DeviceStateError::WrongChannel(*note_id, channel_id));
+                }
+            }
+        }
+        Ok(DeviceState {
+            notes: state.notes,
+            channels: state.channels,
+            unused_notes: state.unused_notes,
+        })
+    }
+}
+
 impl DeviceState {
     /// Create a new midi device state with the given midi channels and polyphonic notes.
     ///
@@ -111,7 +239,7 @@ impl DeviceState {
 
     fn note_on(&mut self, channel_id: Channel, note: &ChromaticNote, velocity: Velocity) {
         let channel = &self[channel_id];
-        if let None = self.find_note_id(channel, note) {
+        if self.find_note_id(channel, note).is_none() {
             if let Some(note_id) = self.unused_notes.pop() {
                 self.notes[note_id].on(channel_id, note, velocity);
 
@@ -163,6 +291,39 @@ impl DeviceState {
     pub fn note_by_note_index(&self, id: NoteStateId) -> &'_ NoteState {
         &self.notes[id]
     }
+
+    /// Notes that are currently active on the given channel, in the order they were pressed.
+    pub fn active_notes(&self, channel_id: Channel) -> Vec<ChromaticNote> {
+        self[channel_id]
+            .active_notes
+            .iter()
+            .map(|note_id| self.notes[*note_id].note)
+            .collect()
+    }
+
+    /// Chord that best matches the active notes on the given channel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use midi_device::*;
+    /// use midi_events::*;
+    /// use music_notes::*;
+    ///
+    /// let mut device = DeviceState::new(1, 8);
+    /// for note in ["E4", "G4", "C5"] {
+    ///     device.apply_event(&Event::NoteOn(1, note.parse().unwrap(), 64));
+    /// }
+    /// assert_eq!(device.chord(1).unwrap().to_string(), "C/E");
+    /// ```
+    pub fn chord(&self, channel_id: Channel) -> Option<RecognizedChord> {
+        recognize_chord(&self.active_notes(channel_id))
+    }
+
+    /// Pitch classes of the active notes on the given channel.
+    pub fn pitch_class_set(&self, channel_id: Channel) -> PitchClassSet {
+        PitchClassSet::from_notes(&self.active_notes(channel_id))
+    }
 }
 
 impl Index<Channel> for DeviceState {
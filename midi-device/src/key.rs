@@ -0,0 +1,51 @@
+use midi_events::Event;
+use music_notes::{DetectedKey, KeyDetector};
+
+/// Tracks the key of the notes played on a device.
+///
+/// # Example
+///
+/// ```
+/// use midi_device::*;
+/// use midi_events::*;
+/// use music_notes::*;
+///
+/// let mut tracker = KeyTracker::default();
+/// for note in ["A3", "C4", "E4", "A4", "B4", "C5", "E5", "G#4", "A4"] {
+///     tracker.apply_event(&Event::NoteOn(1, note.parse().unwrap(), 64));
+/// }
+/// let key = tracker.key().unwrap().key;
+/// assert_eq!(key.to_string(), "A minor");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct KeyTracker {
+    detector: KeyDetector,
+}
+
+impl KeyTracker {
+    /// Create a key tracker that uses the last `window` played notes.
+    pub fn new(window: usize) -> KeyTracker {
+        KeyTracker {
+            detector: KeyDetector::new(window),
+        }
+    }
+
+    /// Apply the given midi event. Only note-on events with a velocity are used.
+    pub fn apply_event(&mut self, event: &Event) {
+        if let Event::NoteOn(_channel_id, note, velocity) = event {
+            if *velocity > 0 {
+                self.detector.add_note(*note);
+            }
+        }
+    }
+
+    /// Most likely key of the played notes.
+    pub fn key(&self) -> Option<DetectedKey> {
+        self.detector.key()
+    }
+
+    /// Forget all played notes.
+    pub fn reset(&mut self) {
+        self.detector.reset();
+    }
+}
@@ -1,7 +1,11 @@
 mod channel;
+mod clock;
 mod device;
+mod key;
 mod note;
 
 pub use channel::*;
+pub use clock::*;
 pub use device::*;
+pub use key::*;
 pub use note::*;
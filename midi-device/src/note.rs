@@ -4,6 +4,7 @@ use music_notes::ChromaticNote;
 pub type NoteStateId = usize;
 
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NoteState {
     pub channel_id: Option<Channel>,
     pub note: ChromaticNote,
@@ -1,88 +1,219 @@
 //! Decode a midi event.
-use music_notes::{ChromaticNote, ChromaticScale, Scale};
+use std::{error::Error, fmt::Display};
+
+use music_notes::ChromaticNote;
 
 use crate::{
-    Channel, Event, Modulation, StatusCode, Value, Velocity, CONTROLLER_ALL_NOTES_OFF,
-    CONTROLLER_CHANNEL_PAN, CONTROLLER_CHANNEL_VOLUME, STATUS_CONTROLLER, STATUS_KEY_PRESSURE,
-    STATUS_MODULATION_WHEEL, STATUS_NOTE_OFF, STATUS_NOTE_ON, STATUS_PROGRAM_CHANGE,
-    STATUS_SYSTEM_EXCLUSIVE,
+    Channel, Event, PitchBend, StatusCode, SystemExclusive, Value, Velocity,
+    CONTROLLER_ALL_NOTES_OFF, CONTROLLER_CHANNEL_PAN, CONTROLLER_CHANNEL_VOLUME, PITCH_BEND_MIN,
+    STATUS_ACTIVE_SENSING, STATUS_CHANNEL_PRESSURE, STATUS_CONTINUE, STATUS_CONTROLLER,
+    STATUS_KEY_PRESSURE, STATUS_NOTE_OFF, STATUS_NOTE_ON, STATUS_PITCH_BEND, STATUS_PROGRAM_CHANGE,
+    STATUS_SONG_POSITION, STATUS_SONG_SELECT, STATUS_START, STATUS_STOP, STATUS_SYSTEM_EXCLUSIVE,
+    STATUS_SYSTEM_RESET, STATUS_TIME_CODE_QUARTER_FRAME, STATUS_TIMING_CLOCK, STATUS_TUNE_REQUEST,
 };
 
-fn decode_status_and_channel(midi_message: &[u8], index: &mut usize) -> (StatusCode, Channel) {
-    let status_code = midi_message[*index] & 0xf0;
-    let channel = (midi_message[*index] & 0x0f) + 1;
-    *index += 1;
-    (status_code, channel)
+/// Status bytes that the midi specification reserves, they don't have a meaning.
+const RESERVED_STATUS_BYTES: [u8; 4] = [0xf4, 0xf5, 0xf9, 0xfd];
+
+/// Reason why a midi message couldn't be decoded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The message has less bytes than its status byte requires.
+    Truncated { expected: usize, actual: usize },
+    /// The message starts with a data byte instead of a status byte.
+    UnexpectedDataByte(u8),
+    /// The status byte is reserved by the midi specification.
+    ReservedStatus(u8),
+    /// The note byte isn't a midi note number.
+    InvalidNote(u8),
+    /// A data byte has its highest bit set.
+    InvalidDataByte(u8),
+    /// A system exclusive message doesn't end with `0xF7`.
+    UnterminatedSystemExclusive,
+    /// A system exclusive message is too short to contain a manufacturer id.
+    MissingManufacturerId,
+    /// A system exclusive message is longer than [`crate::MAX_SYSTEM_EXCLUSIVE_LENGTH`].
+    SystemExclusiveTooLong,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Truncated { expected, actual } => {
+                write!(f, "midi message has {actual} bytes, expected {expected}")
+            }
+            Self::UnexpectedDataByte(byte) => {
+                write!(f, "expected a status byte, found data byte {byte:#04x}")
+            }
+            Self::ReservedStatus(byte) => write!(f, "status byte {byte:#04x} is reserved"),
+            Self::InvalidNote(byte) => write!(f, "{byte} isn't a midi note"),
+            Self::InvalidDataByte(byte) => write!(f, "{byte:#04x} isn't a data byte"),
+            Self::UnterminatedSystemExclusive => {
+                write!(f, "system exclusive message doesn't end with 0xf7")
+            }
+            Self::MissingManufacturerId => {
+                write!(f, "system exclusive message doesn't have a manufacturer id")
+            }
+            Self::SystemExclusiveTooLong => write!(
+                f,
+                "system exclusive message is longer than {} bytes",
+                crate::MAX_SYSTEM_EXCLUSIVE_LENGTH
+            ),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+fn decode_status_and_channel(
+    midi_message: &[u8],
+    index: &mut usize,
+) -> Result<(StatusCode, Channel), DecodeError> {
+    let byte = read_byte(midi_message, index, 1)?;
+    if byte < 0x80 {
+        return Err(DecodeError::UnexpectedDataByte(byte));
+    }
+    if RESERVED_STATUS_BYTES.contains(&byte) {
+        return Err(DecodeError::ReservedStatus(byte));
+    }
+    let status_code = byte & 0xf0;
+    let channel = (byte & 0x0f) + 1;
+    Ok((status_code, channel))
+}
+
+/// Number of data bytes following the status byte.
+pub(crate) fn data_length(status_byte: u8) -> usize {
+    match status_byte & 0xf0 {
+        STATUS_PROGRAM_CHANGE | STATUS_CHANNEL_PRESSURE => 1,
+        STATUS_SYSTEM_EXCLUSIVE => match status_byte {
+            STATUS_TIME_CODE_QUARTER_FRAME | STATUS_SONG_SELECT => 1,
+            STATUS_SONG_POSITION => 2,
+            _ => 0,
+        },
+        _ => 2,
+    }
 }
 
 /// Decode the note part of a midi message to a music note.
 ///
 /// # Example
 /// ```
-/// use midi_events::decode_note;
+/// use midi_events::{decode_note, DecodeError};
 /// use music_notes::{ChromaticNote, ChromaticTone};
 ///
 /// let mut index = 0;
-/// assert_eq!(decode_note(&[60], &mut index), ChromaticNote::new(ChromaticTone::C, 4));
+/// let message = [60, 0, 128];
+/// assert_eq!(decode_note(&message, &mut index), Ok(ChromaticNote::new(ChromaticTone::C, 4)));
+/// assert_eq!(decode_note(&message, &mut index), Ok(ChromaticNote::new(ChromaticTone::C, -1)));
+/// assert_eq!(decode_note(&message, &mut index), Err(DecodeError::InvalidNote(128)));
 /// ```
-pub fn decode_note(midi_message: &[u8], index: &mut usize) -> ChromaticNote {
-    let scale = ChromaticScale::default();
-    let midi_note = midi_message[*index];
-    let octave = (midi_note / scale.tones_per_octave() as u8) - 1;
-    let note_index = midi_note % scale.tones_per_octave() as u8;
+pub fn decode_note(midi_message: &[u8], index: &mut usize) -> Result<ChromaticNote, DecodeError> {
+    let midi_note = read_byte(midi_message, index, *index + 1)?;
+    ChromaticNote::from_midi(midi_note).map_err(|_| DecodeError::InvalidNote(midi_note))
+}
 
-    *index += 1;
+fn decode_velocity(midi_message: &[u8], index: &mut usize) -> Result<Velocity, DecodeError> {
+    decode_value(midi_message, index)
+}
 
-    ChromaticNote::new(note_index, octave)
+/// Pitch bend is sent as a 14 bit value with the center at 8192.
+fn decode_pitch_bend(midi_message: &[u8], index: &mut usize) -> Result<PitchBend, DecodeError> {
+    let value = decode_14_bit_value(midi_message, index)?;
+    Ok((value as i32 + PITCH_BEND_MIN as i32) as PitchBend)
 }
 
-fn decode_velocity(midi_message: &[u8], index: &mut usize) -> Velocity {
-    let velocity = midi_message[*index];
-    *index += 1;
-    velocity
+/// Decode two data bytes as a 14 bit value, least significant 7 bits first.
+fn decode_14_bit_value(midi_message: &[u8], index: &mut usize) -> Result<u16, DecodeError> {
+    let (least_significant, most_significant) = decode_two_values(midi_message, index)?;
+    Ok(((most_significant as u16) << 7) | least_significant as u16)
 }
 
-fn decode_modulation(midi_message: &[u8], index: &mut usize) -> Modulation {
-    let (value1, value2) = decode_two_values(midi_message, index);
-    let modulation = ((value1 as u16) << 7) + value2 as u16;
-    modulation
+fn decode_value(midi_message: &[u8], index: &mut usize) -> Result<Value, DecodeError> {
+    let value = read_byte(midi_message, index, *index + 1)?;
+    if value > 0x7f {
+        return Err(DecodeError::InvalidDataByte(value));
+    }
+    Ok(value)
 }
-fn decode_value(midi_message: &[u8], index: &mut usize) -> Value {
-    let value = midi_message[*index];
-    *index += 1;
-    value
+
+fn decode_two_values(
+    midi_message: &[u8],
+    index: &mut usize,
+) -> Result<(Value, Value), DecodeError> {
+    Ok((
+        decode_value(midi_message, index)?,
+        decode_value(midi_message, index)?,
+    ))
 }
-fn decode_two_values(midi_message: &[u8], index: &mut usize) -> (Value, Value) {
-    (
-        decode_value(midi_message, index),
-        decode_value(midi_message, index),
-    )
+
+/// Read the byte at the index. `expected` is the length of the message that is reported when
+/// the message is too short.
+fn read_byte(midi_message: &[u8], index: &mut usize, expected: usize) -> Result<u8, DecodeError> {
+    let byte = *midi_message.get(*index).ok_or(DecodeError::Truncated {
+        expected,
+        actual: midi_message.len(),
+    })?;
+    *index += 1;
+    Ok(byte)
 }
 
-fn decode_midi_event(midi_message: &[u8]) -> Event {
+/// Decode the midi event at the start of the message.
+///
+/// Returns the event and the number of bytes of the message that were used.
+///
+/// # Example
+/// ```
+/// use midi_events::*;
+/// use music_notes::*;
+///
+/// let (event, length) = decode(&[0x91, 60, 100, 0x81]).unwrap();
+/// assert!(matches!(event, Event::NoteOn(2, note, 100) if note.to_string() == "C4"));
+/// assert_eq!(length, 3);
+///
+/// assert_eq!(
+///     decode(&[0x91, 60]).unwrap_err(),
+///     DecodeError::Truncated { expected: 3, actual: 2 }
+/// );
+/// assert_eq!(decode(&[60, 100]).unwrap_err(), DecodeError::UnexpectedDataByte(60));
+/// assert_eq!(decode(&[0xf4]).unwrap_err(), DecodeError::ReservedStatus(0xf4));
+/// assert!(Event::try_from(&[][..]).is_err());
+/// ```
+pub fn decode(midi_message: &[u8]) -> Result<(Event, usize), DecodeError> {
     let mut index = 0;
-    let (status_code, channel) = decode_status_and_channel(midi_message, &mut index);
-    match status_code {
+    let (status_code, channel) = decode_status_and_channel(midi_message, &mut index)?;
+    let status_byte = midi_message[0];
+    let expected = 1 + data_length(status_byte);
+    if midi_message.len() < expected {
+        return Err(DecodeError::Truncated {
+            expected,
+            actual: midi_message.len(),
+        });
+    }
+
+    let event = match status_code {
         STATUS_NOTE_ON => Event::NoteOn(
             channel,
-            decode_note(midi_message, &mut index),
-            decode_velocity(midi_message, &mut index),
+            decode_note(midi_message, &mut index)?,
+            decode_velocity(midi_message, &mut index)?,
         ),
         STATUS_NOTE_OFF => Event::NoteOff(
             channel,
-            decode_note(midi_message, &mut index),
-            decode_velocity(midi_message, &mut index),
+            decode_note(midi_message, &mut index)?,
+            decode_velocity(midi_message, &mut index)?,
         ),
         STATUS_KEY_PRESSURE => Event::KeyPressure(
             channel,
-            decode_note(midi_message, &mut index),
-            decode_velocity(midi_message, &mut index),
+            decode_note(midi_message, &mut index)?,
+            decode_velocity(midi_message, &mut index)?,
         ),
-        STATUS_MODULATION_WHEEL => {
-            Event::ModulationWheel(channel, decode_modulation(midi_message, &mut index))
+        STATUS_CHANNEL_PRESSURE => {
+            Event::ChannelPressure(channel, decode_value(midi_message, &mut index)?)
+        }
+        STATUS_PITCH_BEND => {
+            Event::PitchBend(channel, decode_pitch_bend(midi_message, &mut index)?)
         }
         STATUS_CONTROLLER => {
-            let (value_1, value_2) = decode_two_values(midi_message, &mut index);
+            let (value_1, value_2) = decode_two_values(midi_message, &mut index)?;
             match value_1 {
                 CONTROLLER_CHANNEL_VOLUME => Event::ChannelVolume(channel, value_2),
                 CONTROLLER_CHANNEL_PAN => Event::ChannelPan(channel, value_2),
@@ -91,15 +222,50 @@ fn decode_midi_event(midi_message: &[u8]) -> Event {
             }
         }
         STATUS_PROGRAM_CHANGE => {
-            Event::ProgramChange(channel, decode_value(midi_message, &mut index))
+            Event::ProgramChange(channel, decode_value(midi_message, &mut index)?)
         }
-        STATUS_SYSTEM_EXCLUSIVE => Event::SystemExclusive(channel),
-        _ => Event::Unknown(status_code),
-    }
+        STATUS_SYSTEM_EXCLUSIVE => decode_system_message(status_byte, midi_message, &mut index)?,
+        _ => {
+            index += data_length(status_byte);
+            Event::Unknown(status_code)
+        }
+    };
+    Ok((event, index))
+}
+
+/// Decode system exclusive, system common and system real time messages.
+fn decode_system_message(
+    status_byte: u8,
+    midi_message: &[u8],
+    index: &mut usize,
+) -> Result<Event, DecodeError> {
+    let event = match status_byte {
+        STATUS_SYSTEM_EXCLUSIVE => {
+            let (message, length) = SystemExclusive::decode(midi_message)?;
+            *index = length;
+            Event::SystemExclusive(message)
+        }
+        STATUS_TIME_CODE_QUARTER_FRAME => {
+            let value = decode_value(midi_message, index)?;
+            Event::TimeCodeQuarterFrame(value >> 4, value & 0x0f)
+        }
+        STATUS_SONG_POSITION => Event::SongPosition(decode_14_bit_value(midi_message, index)?),
+        STATUS_SONG_SELECT => Event::SongSelect(decode_value(midi_message, index)?),
+        STATUS_TUNE_REQUEST => Event::TuneRequest,
+        STATUS_TIMING_CLOCK => Event::TimingClock,
+        STATUS_START => Event::Start,
+        STATUS_CONTINUE => Event::Continue,
+        STATUS_STOP => Event::Stop,
+        STATUS_ACTIVE_SENSING => Event::ActiveSensing,
+        STATUS_SYSTEM_RESET => Event::SystemReset,
+        _ => Event::Unknown(status_byte),
+    };
+    Ok(event)
 }
 
-impl From<&[u8]> for Event {
-    fn from(midi_message: &[u8]) -> Self {
-        decode_midi_event(midi_message)
+impl TryFrom<&[u8]> for Event {
+    type Error = DecodeError;
+    fn try_from(midi_message: &[u8]) -> Result<Self, Self::Error> {
+        decode(midi_message).map(|(event, _length)| event)
     }
 }
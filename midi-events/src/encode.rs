@@ -1,52 +1,157 @@
 //! Encode midi event to vector of bytes.
+use std::{error::Error, fmt::Display};
 
-use music_notes::ChromaticNote;
+use music_notes::{ChromaticNote, MidiNoteError};
 
 use crate::{
-    Channel, Event, Modulation, StatusCode, Velocity, CONTROLLER_ALL_NOTES_OFF,
-    CONTROLLER_CHANNEL_PAN, CONTROLLER_CHANNEL_VOLUME, STATUS_CHANNEL_PRESSURE, STATUS_CONTROLLER,
-    STATUS_MODULATION_WHEEL, STATUS_NOTE_OFF, STATUS_NOTE_ON, STATUS_PROGRAM_CHANGE,
+    Channel, Event, PitchBend, SongPosition, StatusCode, Value, Velocity, CONTROLLER_ALL_NOTES_OFF,
+    CONTROLLER_CHANNEL_PAN, CONTROLLER_CHANNEL_VOLUME, PITCH_BEND_MAX, PITCH_BEND_MIN,
+    STATUS_ACTIVE_SENSING, STATUS_CHANNEL_PRESSURE, STATUS_CONTINUE, STATUS_CONTROLLER,
+    STATUS_KEY_PRESSURE, STATUS_NOTE_OFF, STATUS_NOTE_ON, STATUS_PITCH_BEND, STATUS_PROGRAM_CHANGE,
+    STATUS_SONG_POSITION, STATUS_SONG_SELECT, STATUS_START, STATUS_STOP, STATUS_SYSTEM_RESET,
+    STATUS_TIME_CODE_QUARTER_FRAME, STATUS_TIMING_CLOCK, STATUS_TUNE_REQUEST,
 };
 
+/// Reason why an event couldn't be encoded.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EncodeError {
+    /// The note is outside the range of midi notes.
+    InvalidNote(MidiNoteError),
+    /// Channels are numbered 1 to 16.
+    InvalidChannel(Channel),
+    /// Data bytes, like velocities and controller values, can't be above 127.
+    InvalidDataByte(Value),
+    /// Pitch bends range from [`PITCH_BEND_MIN`] to [`PITCH_BEND_MAX`].
+    InvalidPitchBend(PitchBend),
+    /// Time code quarter frames have a message type of 0 to 7 and a value of 0 to 15.
+    InvalidTimeCodeQuarterFrame(u8, u8),
+    /// Song positions are 14 bit values, they can't be above 16383.
+    InvalidSongPosition(SongPosition),
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidNote(error) => write!(f, "{error}"),
+            Self::InvalidChannel(channel) => {
+                write!(
+                    f,
+                    "channel {channel} isn't a midi channel, expected 1 to 16"
+                )
+            }
+            Self::InvalidDataByte(value) => {
+                write!(f, "{value} isn't a midi data byte, expected 0 to 127")
+            }
+            Self::InvalidPitchBend(pitch_bend) => write!(
+                f,
+                "pitch bend {pitch_bend} is out of range, expected {PITCH_BEND_MIN} to {PITCH_BEND_MAX}"
+            ),
+            Self::InvalidTimeCodeQuarterFrame(message_type, value) => write!(
+                f,
+                "time code quarter frame {message_type}, {value} is out of range, expected a message type of 0 to 7 and a value of 0 to 15"
+            ),
+            Self::InvalidSongPosition(position) => write!(
+                f,
+                "song position {position} is out of range, expected 0 to 16383"
+            ),
+        }
+    }
+}
+
+impl Error for EncodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidNote(error) => Some(error),
+            Self::InvalidChannel(_)
+            | Self::InvalidDataByte(_)
+            | Self::InvalidPitchBend(_)
+            | Self::InvalidTimeCodeQuarterFrame(_, _)
+            | Self::InvalidSongPosition(_) => None,
+        }
+    }
+}
+
+impl From<MidiNoteError> for EncodeError {
+    fn from(error: MidiNoteError) -> Self {
+        Self::InvalidNote(error)
+    }
+}
+
 trait MidiEventEncoder {
-    fn encode_controller_channel(&mut self, channel: Channel, controller_byte: u8) {
-        self.encode_status_and_channel(STATUS_CONTROLLER, channel);
-        self.write_byte(controller_byte);
+    fn encode_controller_channel(
+        &mut self,
+        channel: Channel,
+        controller_byte: u8,
+    ) -> Result<(), EncodeError> {
+        self.encode_status_and_channel(STATUS_CONTROLLER, channel)?;
+        self.encode_controller(controller_byte)
     }
 
-    fn encode_status_and_channel(&mut self, status: StatusCode, channel: Channel) {
+    /// Channels outside 1 to 16 are an error, they would change the status of the message.
+    fn encode_status_and_channel(
+        &mut self,
+        status: StatusCode,
+        channel: Channel,
+    ) -> Result<(), EncodeError> {
+        if !(1..=16).contains(&channel) {
+            return Err(EncodeError::InvalidChannel(channel));
+        }
         let zero_based_channel = channel - 1;
         let byte = status | zero_based_channel;
         self.write_byte(byte);
+        Ok(())
     }
 
-    fn encode_note(&mut self, note: ChromaticNote) {
-        let byte = i32::from(note) as u8;
+    /// Notes outside the midi range are an error, they aren't moved to another note.
+    fn encode_note(&mut self, note: ChromaticNote) -> Result<(), EncodeError> {
+        let byte = note.to_midi()?;
         self.write_byte(byte);
+        Ok(())
     }
 
-    fn encode_velocity(&mut self, velocity: Velocity) {
-        self.write_byte(velocity);
+    fn encode_velocity(&mut self, velocity: Velocity) -> Result<(), EncodeError> {
+        self.encode_value(velocity)
     }
 
-    fn encode_controller_with_value(&mut self, controller: u8, value: u8) {
-        self.encode_controller(controller);
-        self.encode_value(value);
+    fn encode_controller_with_value(
+        &mut self,
+        controller: u8,
+        value: u8,
+    ) -> Result<(), EncodeError> {
+        self.encode_controller(controller)?;
+        self.encode_value(value)
     }
 
-    fn encode_controller(&mut self, controller: u8) {
-        self.write_byte(controller);
+    fn encode_controller(&mut self, controller: u8) -> Result<(), EncodeError> {
+        self.encode_value(controller)
     }
 
-    fn encode_value(&mut self, value: Velocity) {
+    /// Values above 127 are an error, they would be read as a status byte.
+    fn encode_value(&mut self, value: Value) -> Result<(), EncodeError> {
+        if value > 0x7f {
+            return Err(EncodeError::InvalidDataByte(value));
+        }
         self.write_byte(value);
+        Ok(())
     }
 
-    fn encode_modulation(&mut self, modulation: Modulation) {
-        let byte2 = (modulation & 0b01111111) as u8;
-        let byte1 = (modulation >> 7) as u8;
-        self.write_byte(byte1);
-        self.write_byte(byte2);
+    /// Pitch bend is sent as a 14 bit value with the center at 8192. Pitch bends outside
+    /// [`PITCH_BEND_MIN`] to [`PITCH_BEND_MAX`] are an error, they don't fit in 14 bits.
+    fn encode_pitch_bend(&mut self, pitch_bend: PitchBend) -> Result<(), EncodeError> {
+        if !(PITCH_BEND_MIN..=PITCH_BEND_MAX).contains(&pitch_bend) {
+            return Err(EncodeError::InvalidPitchBend(pitch_bend));
+        }
+        let value = (pitch_bend as i32 - PITCH_BEND_MIN as i32) as u16;
+        self.encode_14_bit_value(value);
+        Ok(())
+    }
+
+    /// Encode a 14 bit value as two data bytes, least significant 7 bits first. The caller
+    /// checks that the value fits in 14 bits.
+    fn encode_14_bit_value(&mut self, value: u16) {
+        debug_assert!(value <= 0x3fff);
+        self.write_byte((value & 0x7f) as u8);
+        self.write_byte((value >> 7) as u8);
     }
 
     fn write_byte(&mut self, byte: u8);
@@ -58,53 +163,222 @@ impl MidiEventEncoder for Vec<u8> {
 }
 
 impl Event {
-    pub fn encode_into(&self, r_result: &mut Vec<u8>) {
+    /// Append the midi bytes of the event.
+    ///
+    /// Nothing is appended when the event can't be encoded, for example a note outside the
+    /// midi range, a channel outside 1 to 16, a value above 127 or a pitch bend or song position
+    /// that doesn't fit in 14 bits.
+    ///
+    /// # Example
+    /// ```
+    /// use midi_events::*;
+    /// use music_notes::*;
+    ///
+    /// let mut bytes = Vec::new();
+    /// Event::PitchBend(1, 0).encode_into(&mut bytes).unwrap();
+    /// assert_eq!(bytes, [0xe0, 0x00, 0x40]);
+    ///
+    /// // C10 isn't a midi note.
+    /// let c10 = ChromaticNote::new(ChromaticTone::C, 10);
+    /// assert_eq!(
+    ///     Event::NoteOn(1, c10, 100).encode_into(&mut bytes),
+    ///     Err(EncodeError::InvalidNote(MidiNoteError::NoteOutOfRange(c10)))
+    /// );
+    /// assert_eq!(bytes, [0xe0, 0x00, 0x40]);
+    ///
+    /// let mut bytes = Vec::new();
+    /// Event::SongPosition(300).encode_into(&mut bytes).unwrap();
+    /// assert_eq!(bytes, [0xf2, 0x2c, 0x02]);
+    ///
+    /// // Channels and data bytes that don't fit in a midi message are rejected.
+    /// let c4 = ChromaticNote::new(ChromaticTone::C, 4);
+    /// let invalid = [
+    ///     (Event::NoteOn(1, c4, 200), EncodeError::InvalidDataByte(200)),
+    ///     (Event::NoteOn(0, c4, 100), EncodeError::InvalidChannel(0)),
+    ///     (Event::NoteOn(17, c4, 100), EncodeError::InvalidChannel(17)),
+    ///     (Event::Controller(1, 128, 0), EncodeError::InvalidDataByte(128)),
+    ///     (Event::PitchBend(0, 0), EncodeError::InvalidChannel(0)),
+    ///     (Event::PitchBend(1, 8192), EncodeError::InvalidPitchBend(8192)),
+    ///     (Event::PitchBend(1, -8193), EncodeError::InvalidPitchBend(-8193)),
+    ///     (Event::SongSelect(255), EncodeError::InvalidDataByte(255)),
+    ///     (
+    ///         Event::TimeCodeQuarterFrame(8, 0),
+    ///         EncodeError::InvalidTimeCodeQuarterFrame(8, 0),
+    ///     ),
+    ///     (
+    ///         Event::TimeCodeQuarterFrame(0, 16),
+    ///         EncodeError::InvalidTimeCodeQuarterFrame(0, 16),
+    ///     ),
+    ///     (Event::SongPosition(0x4000), EncodeError::InvalidSongPosition(0x4000)),
+    ///     (
+    ///         Event::SystemExclusive(SystemExclusive::new(
+    ///             ManufacturerId::Short(0x41),
+    ///             vec![0x10, 0xf7],
+    ///         )),
+    ///         EncodeError::InvalidDataByte(0xf7),
+    ///     ),
+    /// ];
+    /// for (event, error) in invalid {
+    ///     let mut bytes = Vec::new();
+    ///     assert_eq!(event.encode_into(&mut bytes), Err(error));
+    ///     assert!(bytes.is_empty());
+    /// }
+    /// ```
+    pub fn encode_into(&self, r_result: &mut Vec<u8>) -> Result<(), EncodeError> {
+        let start = r_result.len();
+        let result = self.encode(r_result);
+        if result.is_err() {
+            r_result.truncate(start);
+        }
+        result
+    }
+
+    fn encode(&self, r_result: &mut Vec<u8>) -> Result<(), EncodeError> {
         match self {
             Self::AllNotesOff(channel) => {
-                r_result.encode_controller_channel(*channel, CONTROLLER_ALL_NOTES_OFF);
-                r_result.encode_value(0);
+                r_result.encode_controller_channel(*channel, CONTROLLER_ALL_NOTES_OFF)?;
+                r_result.encode_value(0)?;
             }
             Self::NoteOn(channel, note, velocity) => {
-                r_result.encode_status_and_channel(STATUS_NOTE_ON, *channel);
-                r_result.encode_note(*note);
-                r_result.encode_velocity(*velocity);
+                r_result.encode_status_and_channel(STATUS_NOTE_ON, *channel)?;
+                r_result.encode_note(*note)?;
+                r_result.encode_velocity(*velocity)?;
             }
             Self::NoteOff(channel, note, velocity) => {
-                r_result.encode_status_and_channel(STATUS_NOTE_OFF, *channel);
-                r_result.encode_note(*note);
-                r_result.encode_velocity(*velocity);
+                r_result.encode_status_and_channel(STATUS_NOTE_OFF, *channel)?;
+                r_result.encode_note(*note)?;
+                r_result.encode_velocity(*velocity)?;
             }
             Self::ChannelPan(channel, value) => {
-                r_result.encode_status_and_channel(STATUS_CONTROLLER, *channel);
-                r_result.encode_controller_with_value(CONTROLLER_CHANNEL_PAN, *value);
+                r_result.encode_status_and_channel(STATUS_CONTROLLER, *channel)?;
+                r_result.encode_controller_with_value(CONTROLLER_CHANNEL_PAN, *value)?;
             }
             Self::ChannelVolume(channel, volume) => {
-                r_result.encode_status_and_channel(STATUS_CONTROLLER, *channel);
-                r_result.encode_controller_with_value(CONTROLLER_CHANNEL_VOLUME, *volume);
+                r_result.encode_status_and_channel(STATUS_CONTROLLER, *channel)?;
+                r_result.encode_controller_with_value(CONTROLLER_CHANNEL_VOLUME, *volume)?;
             }
             Self::KeyPressure(channel, note, pressure) => {
-                r_result.encode_status_and_channel(STATUS_CHANNEL_PRESSURE, *channel);
-                r_result.encode_note(*note);
-                r_result.encode_value(*pressure);
+                r_result.encode_status_and_channel(STATUS_KEY_PRESSURE, *channel)?;
+                r_result.encode_note(*note)?;
+                r_result.encode_value(*pressure)?;
             }
             Self::ProgramChange(channel, program) => {
-                r_result.encode_status_and_channel(STATUS_PROGRAM_CHANGE, *channel);
-                r_result.encode_value(*program);
+                r_result.encode_status_and_channel(STATUS_PROGRAM_CHANGE, *channel)?;
+                r_result.encode_value(*program)?;
             }
             Self::Controller(channel, value1, value2) => {
-                r_result.encode_status_and_channel(STATUS_CONTROLLER, *channel);
-                r_result.encode_value(*value1);
-                r_result.encode_value(*value2);
+                r_result.encode_status_and_channel(STATUS_CONTROLLER, *channel)?;
+                r_result.encode_controller_with_value(*value1, *value2)?;
+            }
+            Self::ChannelPressure(channel, pressure) => {
+                r_result.encode_status_and_channel(STATUS_CHANNEL_PRESSURE, *channel)?;
+                r_result.encode_value(*pressure)?;
             }
-            Self::ModulationWheel(channel, modulation) => {
-                r_result.encode_status_and_channel(STATUS_MODULATION_WHEEL, *channel);
-                r_result.encode_modulation(*modulation);
+            Self::PitchBend(channel, pitch_bend) => {
+                r_result.encode_status_and_channel(STATUS_PITCH_BEND, *channel)?;
+                r_result.encode_pitch_bend(*pitch_bend)?;
             }
+            Self::SystemExclusive(message) => {
+                let start = r_result.len();
+                message.encode_into(r_result);
+                // Everything between the start and end of the message must be a data byte.
+                let data = &r_result[start + 1..r_result.len() - 1];
+                if let Some(byte) = data.iter().find(|byte| **byte > 0x7f) {
+                    return Err(EncodeError::InvalidDataByte(*byte));
+                }
+            }
+            Self::TimeCodeQuarterFrame(message_type, value) => {
+                if *message_type > 0x07 || *value > 0x0f {
+                    return Err(EncodeError::InvalidTimeCodeQuarterFrame(
+                        *message_type,
+                        *value,
+                    ));
+                }
+                r_result.write_byte(STATUS_TIME_CODE_QUARTER_FRAME);
+                r_result.encode_value((message_type << 4) | value)?;
+            }
+            Self::SongPosition(position) => {
+                if *position > 0x3fff {
+                    return Err(EncodeError::InvalidSongPosition(*position));
+                }
+                r_result.write_byte(STATUS_SONG_POSITION);
+                r_result.encode_14_bit_value(*position);
+            }
+            Self::SongSelect(song) => {
+                r_result.write_byte(STATUS_SONG_SELECT);
+                r_result.encode_value(*song)?;
+            }
+            Self::TuneRequest => r_result.write_byte(STATUS_TUNE_REQUEST),
+            Self::TimingClock => r_result.write_byte(STATUS_TIMING_CLOCK),
+            Self::Start => r_result.write_byte(STATUS_START),
+            Self::Continue => r_result.write_byte(STATUS_CONTINUE),
+            Self::Stop => r_result.write_byte(STATUS_STOP),
+            Self::ActiveSensing => r_result.write_byte(STATUS_ACTIVE_SENSING),
+            Self::SystemReset => r_result.write_byte(STATUS_SYSTEM_RESET),
+            Self::Unknown(status) => r_result.push(*status),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use music_notes::{ChromaticNote, ChromaticTone};
+
+    use crate::{decode, Event, PITCH_BEND_MAX, PITCH_BEND_MIN};
+
+    fn assert_round_trip(event: Event) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        event.encode_into(&mut bytes).unwrap();
+        assert_eq!(decode(&bytes), Ok((event, bytes.len())));
+        bytes
+    }
 
-            _ => {
-                println!("{self:#?}");
-                unimplemented!()
+    /// All channel voice messages survive a round trip on every channel.
+    #[test]
+    fn channel_messages_round_trip() {
+        let note = ChromaticNote::new(ChromaticTone::FSharp, 3);
+        for channel in 1..=16 {
+            let mut events = vec![
+                Event::NoteOn(channel, note, 100),
+                Event::NoteOff(channel, note, 0),
+                Event::KeyPressure(channel, note, 42),
+                Event::Controller(channel, 1, 64),
+                Event::ChannelVolume(channel, 100),
+                Event::ChannelPan(channel, 0),
+                Event::AllNotesOff(channel),
+                Event::ProgramChange(channel, 5),
+                Event::ChannelPressure(channel, 127),
+            ];
+            for pitch_bend in [PITCH_BEND_MIN, -1, 0, 1, PITCH_BEND_MAX] {
+                events.push(Event::PitchBend(channel, pitch_bend));
+            }
+            for event in events {
+                let bytes = assert_round_trip(event);
+                assert_eq!(bytes[0] & 0x0f, channel - 1);
             }
         }
     }
+
+    /// System common and real time messages survive a round trip.
+    #[test]
+    fn system_messages_round_trip() {
+        let events = [
+            Event::TimeCodeQuarterFrame(7, 0x03),
+            Event::TimeCodeQuarterFrame(0, 0x0f),
+            Event::SongPosition(0),
+            Event::SongPosition(0x3fff),
+            Event::SongSelect(12),
+            Event::TuneRequest,
+            Event::TimingClock,
+            Event::Start,
+            Event::Continue,
+            Event::Stop,
+            Event::ActiveSensing,
+            Event::SystemReset,
+        ];
+        for event in events {
+            assert_round_trip(event);
+        }
+    }
 }
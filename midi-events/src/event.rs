@@ -1,40 +1,111 @@
+use std::fmt::Display;
+
 use music_notes::ChromaticNote;
 
+use crate::SystemExclusive;
+
 pub const STATUS_NOTE_OFF: u8 = 0x80;
 pub const STATUS_NOTE_ON: u8 = 0x90;
 pub const STATUS_KEY_PRESSURE: u8 = 0xa0;
 pub const STATUS_CONTROLLER: u8 = 0xb0;
 pub const STATUS_PROGRAM_CHANGE: u8 = 0xc0;
 pub const STATUS_CHANNEL_PRESSURE: u8 = 0xd0;
-pub const STATUS_MODULATION_WHEEL: u8 = 0xe0;
+pub const STATUS_PITCH_BEND: u8 = 0xe0;
 pub const STATUS_SYSTEM_EXCLUSIVE: u8 = 0xf0;
+pub const STATUS_TIME_CODE_QUARTER_FRAME: u8 = 0xf1;
+pub const STATUS_SONG_POSITION: u8 = 0xf2;
+pub const STATUS_SONG_SELECT: u8 = 0xf3;
+pub const STATUS_TUNE_REQUEST: u8 = 0xf6;
+pub const STATUS_TIMING_CLOCK: u8 = 0xf8;
+pub const STATUS_START: u8 = 0xfa;
+pub const STATUS_CONTINUE: u8 = 0xfb;
+pub const STATUS_STOP: u8 = 0xfc;
+pub const STATUS_ACTIVE_SENSING: u8 = 0xfe;
+pub const STATUS_SYSTEM_RESET: u8 = 0xff;
+
+/// Number of timing clock messages per quarter note.
+pub const CLOCKS_PER_QUARTER: Ticks = 24;
+
+pub const PITCH_BEND_MIN: PitchBend = -8192;
+pub const PITCH_BEND_MAX: PitchBend = 8191;
 
 pub const CONTROLLER_CHANNEL_VOLUME: u8 = 7;
 pub const CONTROLLER_CHANNEL_PAN: u8 = 10;
 pub const CONTROLLER_ALL_NOTES_OFF: u8 = 123;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     NoteOn(Channel, ChromaticNote, Velocity),
     NoteOff(Channel, ChromaticNote, Velocity),
 
     KeyPressure(Channel, ChromaticNote, Pressure),
     ProgramChange(Channel, Program),
-    ModulationWheel(Channel, Modulation),
+    ChannelPressure(Channel, Pressure),
+    PitchBend(Channel, PitchBend),
 
     Controller(Channel, u8, u8),
     ChannelPan(Channel, Value),
     ChannelVolume(Channel, Value),
     AllNotesOff(Channel),
-    SystemExclusive(Channel),
+    SystemExclusive(SystemExclusive),
+
+    /// Midi time code quarter frame with the message type (0-7) and its value (0-15).
+    TimeCodeQuarterFrame(u8, u8),
+    SongPosition(SongPosition),
+    SongSelect(Song),
+    TuneRequest,
+
+    /// Sent [`CLOCKS_PER_QUARTER`] times per quarter note by the clock source.
+    TimingClock,
+    /// Start playing from the beginning of the song.
+    Start,
+    /// Continue playing from the current song position.
+    Continue,
+    Stop,
+    ActiveSensing,
+    SystemReset,
     Unknown(u8),
 }
 
+/// Event as it is written in logs, with note names instead of note structs.
+///
+/// # Example
+/// ```
+/// use midi_events::*;
+/// use music_notes::*;
+///
+/// let note = ChromaticNote::new(ChromaticTone::CSharp, 4);
+/// assert_eq!(Event::NoteOn(1, note, 100).to_string(), "NoteOn(1, C#4, 100)");
+/// assert_eq!(Event::ProgramChange(2, 5).to_string(), "ProgramChange(2, 5)");
+/// ```
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoteOn(channel, note, velocity) => {
+                write!(f, "NoteOn({channel}, {note}, {velocity})")
+            }
+            Self::NoteOff(channel, note, velocity) => {
+                write!(f, "NoteOff({channel}, {note}, {velocity})")
+            }
+            Self::KeyPressure(channel, note, pressure) => {
+                write!(f, "KeyPressure({channel}, {note}, {pressure})")
+            }
+            event => write!(f, "{event:?}"),
+        }
+    }
+}
+
 pub type StatusCode = u8;
 pub type Value = u8;
 pub type Channel = Value;
 pub type Velocity = Value;
-pub type Modulation = u16;
+/// Pitch bend relative to the center position, between [`PITCH_BEND_MIN`] and
+/// [`PITCH_BEND_MAX`].
+pub type PitchBend = i16;
 pub type Pressure = Value;
+/// Number of sixteenth notes since the start of the song.
+pub type SongPosition = u16;
+pub type Song = Value;
 pub type Program = Value;
-pub type Ticks = usize;
+pub type Ticks = music_notes::Ticks;
@@ -5,8 +5,12 @@ mod event;
 #[cfg(feature = "with-jack")]
 pub mod jack;
 mod message;
+mod parser;
+mod system_exclusive;
 
 pub use decode::*;
 pub use encode::*;
 pub use event::*;
 pub use message::*;
+pub use parser::*;
+pub use system_exclusive::*;
@@ -6,7 +6,25 @@ use crate::{Event, Ticks};
 /// an event, only when serializing/deserializing the timing is required.
 /// In order to reduce complexity in the API the Message and Event are
 /// two separate data types.
-#[derive(Debug, Copy, Clone)]
+///
+/// With the `serde` feature messages can be stored, for example as JSON.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use midi_events::*;
+/// use music_notes::*;
+///
+/// let message = Message {
+///     delta_time: 120,
+///     event: Event::NoteOn(1, ChromaticNote::new(ChromaticTone::A, 4), 100),
+/// };
+/// let json = serde_json::to_string(&message).unwrap();
+/// assert_eq!(json, r#"{"delta_time":120,"event":{"NoteOn":[1,"A4",100]}}"#);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Message {
     /// Time since the last received midi message in midi ticks.
     pub delta_time: Ticks,
@@ -0,0 +1,181 @@
+//! Parse a stream of midi bytes.
+//!
+//! Serial ports, raw midi devices and midi dumps deliver bytes in chunks that don't line up
+//! with the messages. Messages can be split over chunks, the status byte can be left out when
+//! it equals the status of the previous channel message (running status) and real time
+//! messages can appear between any two bytes, even inside another message.
+use std::collections::VecDeque;
+
+use crate::{
+    data_length, decode, DecodeError, Event, SystemExclusive, MAX_SYSTEM_EXCLUSIVE_LENGTH,
+    STATUS_END_OF_EXCLUSIVE, STATUS_SYSTEM_EXCLUSIVE, STATUS_TIMING_CLOCK,
+};
+
+/// Incremental parser that turns chunks of midi bytes into events.
+///
+/// Bytes are added with [`StreamParser::push`], the events are read by iterating the parser.
+/// The iterator returns `None` when it needs more bytes; after pushing more bytes it continues
+/// where it stopped.
+///
+/// # Example
+/// ```
+/// use midi_events::*;
+/// use music_notes::*;
+///
+/// let c4 = ChromaticNote::new(ChromaticTone::C, 4);
+/// let e4 = ChromaticNote::new(ChromaticTone::E, 4);
+/// let mut parser = StreamParser::default();
+///
+/// // The second note on uses running status, a timing clock interrupts it.
+/// parser.push(&[0x90, 60, 100, 64]);
+/// assert_eq!(parser.next(), Some(Ok(Event::NoteOn(1, c4, 100))));
+/// assert_eq!(parser.next(), None);
+/// parser.push(&[0xf8, 100, 0xf0, 0x7d]);
+/// assert_eq!(parser.next(), Some(Ok(Event::TimingClock)));
+/// assert_eq!(parser.next(), Some(Ok(Event::NoteOn(1, e4, 100))));
+/// assert_eq!(parser.next(), None);
+///
+/// // System exclusive messages can be split as well.
+/// parser.push(&[0x01, 0xf7, 0x80, 60, 0]);
+/// let events: Vec<Event> = parser.by_ref().map(Result::unwrap).collect();
+/// assert_eq!(
+///     events,
+///     [
+///         Event::SystemExclusive(SystemExclusive::new(
+///             ManufacturerId::NON_COMMERCIAL,
+///             vec![0x01]
+///         )),
+///         Event::NoteOff(1, c4, 0),
+///     ]
+/// );
+///
+/// // Data bytes without a status are reported and skipped.
+/// parser.push(&[0xf6, 60, 0x91, 60, 0]);
+/// assert_eq!(parser.next(), Some(Ok(Event::TuneRequest)));
+/// assert_eq!(parser.next(), Some(Err(DecodeError::UnexpectedDataByte(60))));
+/// assert_eq!(parser.next(), Some(Ok(Event::NoteOn(2, c4, 0))));
+///
+/// // System exclusive messages longer than `MAX_SYSTEM_EXCLUSIVE_LENGTH` are dropped, the
+/// // parser continues at the next status byte.
+/// parser.push(&[0xf0, 0x7d]);
+/// parser.push(&vec![0x01; MAX_SYSTEM_EXCLUSIVE_LENGTH]);
+/// parser.push(&[0xf7, 0x90, 60, 100]);
+/// assert_eq!(parser.next(), Some(Err(DecodeError::SystemExclusiveTooLong)));
+/// assert_eq!(parser.next(), Some(Ok(Event::NoteOn(1, c4, 100))));
+/// assert_eq!(parser.next(), None);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct StreamParser {
+    /// Bytes that are pushed but not parsed yet.
+    input: VecDeque<u8>,
+    /// Bytes of the message that is being parsed, starting with its status byte.
+    message: Vec<u8>,
+    /// Status byte of the last channel message.
+    running_status: Option<u8>,
+    /// Skip the data bytes of a system exclusive message that is too long.
+    skip_system_exclusive: bool,
+}
+
+impl StreamParser {
+    /// Add the next chunk of bytes of the stream.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.input.extend(bytes);
+    }
+
+    /// Forget all bytes that aren't parsed yet, the partial message and the running status.
+    pub fn reset(&mut self) {
+        self.input.clear();
+        self.message.clear();
+        self.running_status = None;
+        self.skip_system_exclusive = false;
+    }
+
+    fn is_receiving_system_exclusive(&self) -> bool {
+        self.message.first() == Some(&STATUS_SYSTEM_EXCLUSIVE)
+    }
+
+    /// Decode the message that is being parsed and start a new one.
+    fn finish_message(&mut self) -> Result<Event, DecodeError> {
+        let result = if self.is_receiving_system_exclusive() {
+            SystemExclusive::decode(&self.message)
+                .map(|(message, _length)| Event::SystemExclusive(message))
+        } else {
+            decode(&self.message).map(|(event, _length)| event)
+        };
+        self.message.clear();
+        result
+    }
+}
+
+impl Iterator for StreamParser {
+    type Item = Result<Event, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(byte) = self.input.pop_front() {
+            if byte >= STATUS_TIMING_CLOCK {
+                // Real time messages don't change the message that is being parsed.
+                return Some(decode(&[byte]).map(|(event, _length)| event));
+            }
+
+            if self.skip_system_exclusive {
+                if byte <= 0x7f {
+                    continue;
+                }
+                self.skip_system_exclusive = false;
+                if byte == STATUS_END_OF_EXCLUSIVE {
+                    continue;
+                }
+            }
+
+            if self.is_receiving_system_exclusive() {
+                if byte <= 0x7f {
+                    // Leave room for the end of exclusive byte.
+                    if self.message.len() + 2 > MAX_SYSTEM_EXCLUSIVE_LENGTH {
+                        self.message.clear();
+                        self.skip_system_exclusive = true;
+                        return Some(Err(DecodeError::SystemExclusiveTooLong));
+                    }
+                    self.message.push(byte);
+                    continue;
+                }
+                // Any status byte ends a system exclusive message. Status bytes other than the
+                // end of exclusive byte are parsed again as the start of the next message.
+                if byte != STATUS_END_OF_EXCLUSIVE {
+                    self.input.push_front(byte);
+                }
+                self.message.push(STATUS_END_OF_EXCLUSIVE);
+                return Some(self.finish_message());
+            }
+
+            if byte >= 0x80 {
+                if !self.message.is_empty() {
+                    // The previous message misses data bytes.
+                    self.input.push_front(byte);
+                    let error = DecodeError::Truncated {
+                        expected: 1 + data_length(self.message[0]),
+                        actual: self.message.len(),
+                    };
+                    self.message.clear();
+                    return Some(Err(error));
+                }
+                // Only channel messages can be continued with running status.
+                self.running_status = (byte < STATUS_SYSTEM_EXCLUSIVE).then_some(byte);
+                self.message.push(byte);
+                if byte == STATUS_SYSTEM_EXCLUSIVE {
+                    continue;
+                }
+            } else if !self.message.is_empty() {
+                self.message.push(byte);
+            } else if let Some(status) = self.running_status {
+                self.message.extend([status, byte]);
+            } else {
+                return Some(Err(DecodeError::UnexpectedDataByte(byte)));
+            }
+
+            if self.message.len() == 1 + data_length(self.message[0]) {
+                return Some(self.finish_message());
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,223 @@
+//! System exclusive messages.
+//!
+//! System exclusive (SysEx) messages carry manufacturer specific data, for example patch dumps
+//! of a synthesizer. A message starts with `0xF0`, followed by the manufacturer id and any
+//! number of data bytes, and ends with `0xF7`. Long messages can be split over multiple midi
+//! buffers; [`SystemExclusiveAssembler`] collects the parts.
+use crate::{DecodeError, STATUS_SYSTEM_EXCLUSIVE, STATUS_TIMING_CLOCK};
+
+/// Status byte that ends a system exclusive message.
+pub const STATUS_END_OF_EXCLUSIVE: u8 = 0xf7;
+
+/// Largest system exclusive message, including its start and end byte, that
+/// [`SystemExclusiveAssembler`] collects. Large enough for the sample and patch dumps of most
+/// devices.
+pub const MAX_SYSTEM_EXCLUSIVE_LENGTH: usize = 64 * 1024;
+
+/// Manufacturer of the device a system exclusive message is meant for.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ManufacturerId {
+    /// Single byte id, for example `0x41` for Roland.
+    Short(u8),
+    /// Three byte id that starts with `0x00`, for example `0x00 0x20 0x33` for Access. Only
+    /// the last two bytes are stored.
+    Extended(u8, u8),
+}
+
+impl ManufacturerId {
+    /// Universal system exclusive messages that aren't real time, like sample dumps.
+    pub const UNIVERSAL_NON_REAL_TIME: ManufacturerId = ManufacturerId::Short(0x7e);
+    /// Universal real time system exclusive messages, like midi time code.
+    pub const UNIVERSAL_REAL_TIME: ManufacturerId = ManufacturerId::Short(0x7f);
+    /// Id for non commercial and educational use.
+    pub const NON_COMMERCIAL: ManufacturerId = ManufacturerId::Short(0x7d);
+
+    fn encode_into(&self, r_result: &mut Vec<u8>) {
+        match self {
+            Self::Short(id) => r_result.push(*id),
+            Self::Extended(id1, id2) => r_result.extend([0x00, *id1, *id2]),
+        }
+    }
+}
+
+/// System exclusive message with its manufacturer id and data.
+///
+/// # Example
+/// ```
+/// use midi_events::*;
+///
+/// let message = SystemExclusive::new(ManufacturerId::Short(0x41), vec![0x10, 0x42, 0x12]);
+/// let mut bytes = Vec::new();
+/// message.encode_into(&mut bytes);
+/// assert_eq!(bytes, [0xf0, 0x41, 0x10, 0x42, 0x12, 0xf7]);
+/// assert_eq!(SystemExclusive::decode(&bytes), Ok((message, 6)));
+///
+/// let extended = SystemExclusive::decode(&[0xf0, 0x00, 0x20, 0x33, 0x01, 0xf7]).unwrap().0;
+/// assert_eq!(extended.manufacturer, ManufacturerId::Extended(0x20, 0x33));
+/// assert_eq!(extended.data, [0x01]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemExclusive {
+    pub manufacturer: ManufacturerId,
+    /// Data bytes between the manufacturer id and the end of the message.
+    pub data: Vec<u8>,
+}
+
+impl SystemExclusive {
+    pub fn new(manufacturer: ManufacturerId, data: Vec<u8>) -> SystemExclusive {
+        SystemExclusive { manufacturer, data }
+    }
+
+    /// Decode a complete system exclusive message, from `0xF0` up to and including `0xF7`.
+    ///
+    /// Returns the message and the number of bytes that were used.
+    pub fn decode(midi_message: &[u8]) -> Result<(SystemExclusive, usize), DecodeError> {
+        match midi_message.first() {
+            Some(&STATUS_SYSTEM_EXCLUSIVE) => {}
+            Some(byte) => return Err(DecodeError::UnexpectedDataByte(*byte)),
+            None => {
+                return Err(DecodeError::Truncated {
+                    expected: 1,
+                    actual: 0,
+                })
+            }
+        }
+        let Some(end) = midi_message
+            .iter()
+            .position(|byte| *byte == STATUS_END_OF_EXCLUSIVE)
+        else {
+            return Err(DecodeError::UnterminatedSystemExclusive);
+        };
+        let body = &midi_message[1..end];
+        if let Some(byte) = body.iter().find(|byte| **byte > 0x7f) {
+            return Err(DecodeError::InvalidDataByte(*byte));
+        }
+        let (manufacturer, data) = match body {
+            [0x00, id1, id2, data @ ..] => (ManufacturerId::Extended(*id1, *id2), data),
+            [id, data @ ..] if *id != 0x00 => (ManufacturerId::Short(*id), data),
+            _ => return Err(DecodeError::MissingManufacturerId),
+        };
+        Ok((SystemExclusive::new(manufacturer, data.to_vec()), end + 1))
+    }
+
+    /// Append the midi bytes of the message, including the start and end status bytes.
+    pub fn encode_into(&self, r_result: &mut Vec<u8>) {
+        r_result.push(STATUS_SYSTEM_EXCLUSIVE);
+        self.manufacturer.encode_into(r_result);
+        r_result.extend(&self.data);
+        r_result.push(STATUS_END_OF_EXCLUSIVE);
+    }
+}
+
+/// Collect system exclusive messages that are split over multiple midi buffers.
+///
+/// The first part starts with `0xF0`, the last part ends with `0xF7`. Parts in between
+/// contain only data bytes, or start with `0xF7` as standard midi files do.
+///
+/// # Example
+/// ```
+/// use midi_events::*;
+///
+/// let mut assembler = SystemExclusiveAssembler::default();
+/// assert!(assembler.accepts(&[0xf0, 0x7d, 0x01]));
+/// assert_eq!(assembler.push(&[0xf0, 0x7d, 0x01]), None);
+/// assert!(assembler.is_receiving());
+/// assert_eq!(assembler.push(&[0x02, 0x03]), None);
+/// assert_eq!(
+///     assembler.push(&[0x04, 0xf7]),
+///     Some(Ok(SystemExclusive::new(
+///         ManufacturerId::NON_COMMERCIAL,
+///         vec![0x01, 0x02, 0x03, 0x04]
+///     )))
+/// );
+/// assert!(!assembler.is_receiving());
+/// assert!(!assembler.accepts(&[0x90, 60, 100]));
+///
+/// // A timing clock can be sent in the middle of a message, a note on ends it.
+/// assembler.push(&[0xf0, 0x7d, 0x01]);
+/// assembler.interrupt(&[0xf8]);
+/// assert!(assembler.is_receiving());
+/// assembler.interrupt(&[0x90, 60, 100]);
+/// assert!(!assembler.is_receiving());
+/// assert!(!assembler.accepts(&[0x02, 0xf7]));
+///
+/// // Messages that don't end are dropped instead of growing without a limit.
+/// assembler.push(&[0xf0, 0x7d]);
+/// let part = vec![0x01; 1024];
+/// let mut result = None;
+/// while result.is_none() {
+///     result = assembler.push(&part);
+/// }
+/// assert_eq!(result, Some(Err(DecodeError::SystemExclusiveTooLong)));
+/// assert!(!assembler.is_receiving());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SystemExclusiveAssembler {
+    buffer: Vec<u8>,
+}
+
+impl SystemExclusiveAssembler {
+    /// Is a system exclusive message started that hasn't ended yet.
+    pub fn is_receiving(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+
+    /// Is the midi message (a part of) a system exclusive message. Other messages should be
+    /// decoded with [`crate::decode`].
+    pub fn accepts(&self, midi_message: &[u8]) -> bool {
+        match midi_message.first() {
+            Some(&STATUS_SYSTEM_EXCLUSIVE) => true,
+            Some(&STATUS_END_OF_EXCLUSIVE) => self.is_receiving(),
+            Some(byte) => self.is_receiving() && *byte <= 0x7f,
+            None => false,
+        }
+    }
+
+    /// Add a part of a system exclusive message.
+    ///
+    /// Returns the message when this part completes it, `None` when more parts are expected.
+    /// A new message that starts before the previous one ended replaces it. Messages longer
+    /// than [`MAX_SYSTEM_EXCLUSIVE_LENGTH`] are dropped with an error.
+    pub fn push(&mut self, midi_message: &[u8]) -> Option<Result<SystemExclusive, DecodeError>> {
+        let part = match midi_message.first() {
+            Some(&STATUS_SYSTEM_EXCLUSIVE) => {
+                self.buffer.clear();
+                midi_message
+            }
+            Some(&STATUS_END_OF_EXCLUSIVE) if self.is_receiving() && midi_message.len() > 1 => {
+                &midi_message[1..]
+            }
+            _ if self.is_receiving() => midi_message,
+            _ => return None,
+        };
+        if self.buffer.len() + part.len() > MAX_SYSTEM_EXCLUSIVE_LENGTH {
+            self.buffer.clear();
+            return Some(Err(DecodeError::SystemExclusiveTooLong));
+        }
+        self.buffer.extend_from_slice(part);
+        if self.buffer.last() != Some(&STATUS_END_OF_EXCLUSIVE) {
+            return None;
+        }
+        let result = SystemExclusive::decode(&self.buffer).map(|(message, _length)| message);
+        self.buffer.clear();
+        Some(result)
+    }
+
+    /// Forget a partially received message.
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Handle a midi message that isn't accepted by the assembler. Real time messages can be
+    /// sent in the middle of a system exclusive message, any other status byte ends it and the
+    /// partially received message is forgotten.
+    pub fn interrupt(&mut self, midi_message: &[u8]) {
+        match midi_message.first() {
+            Some(byte) if *byte >= STATUS_TIMING_CLOCK => {}
+            Some(byte) if *byte > 0x7f => self.reset(),
+            _ => {}
+        }
+    }
+}
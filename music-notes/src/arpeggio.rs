@@ -0,0 +1,312 @@
+//! Arpeggio
+//!
+//! An arpeggiator plays the notes of a chord one after another. The pattern decides the order
+//! of the notes, the octave span repeats the notes in higher octaves. Each step also gets a
+//! gate (how long the note sounds) and a velocity from patterns that repeat independently of
+//! the notes, so a three note chord over a four step velocity pattern shifts the accents.
+//!
+//! The arpeggiator only produces steps; timing them and sending note events is up to the user.
+
+use crate::{ChromaticChordType, ChromaticNote, NoteStep};
+
+/// Order in which the notes of an arpeggio are played.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArpeggioPattern {
+    /// Lowest to highest note.
+    #[default]
+    Up,
+    /// Highest to lowest note.
+    Down,
+    /// Lowest to highest and back, without repeating the highest and lowest note.
+    UpDown,
+    /// Highest to lowest and back, without repeating the lowest and highest note.
+    DownUp,
+    /// Random note for each step.
+    Random,
+    /// Order in which the notes were added.
+    AsPlayed,
+}
+
+/// A single step of an arpeggio.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ArpeggioStep {
+    pub note: ChromaticNote,
+    /// Midi velocity of the note.
+    pub velocity: u8,
+    /// Part of the step that the note sounds, between 0 and 1. A gate of 0 is a rest.
+    pub gate: f64,
+}
+
+impl ArpeggioStep {
+    pub fn is_rest(&self) -> bool {
+        self.gate <= 0.0
+    }
+}
+
+/// Generate arpeggio steps from a set of notes.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let mut arpeggiator = Arpeggiator::new(ArpeggioPattern::UpDown);
+/// arpeggiator.octaves = 2;
+/// arpeggiator.velocities = vec![100, 60];
+/// arpeggiator.set_chord(
+///     ChromaticChordType::Major,
+///     ChromaticNote::new(ChromaticTone::C, 4),
+/// );
+///
+/// let names: Vec<String> = arpeggiator
+///     .by_ref()
+///     .take(10)
+///     .map(|step| step.note.to_string())
+///     .collect();
+/// assert_eq!(
+///     names,
+///     ["C4", "E4", "G4", "C5", "E5", "G5", "E5", "C5", "G4", "E4"]
+/// );
+/// let step = arpeggiator.next().unwrap();
+/// assert_eq!(step.note, ChromaticNote::new(ChromaticTone::C, 4));
+/// assert_eq!(step.velocity, 100);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Arpeggiator {
+    pub pattern: ArpeggioPattern,
+    /// Number of octaves the notes are repeated in, at least 1.
+    pub octaves: u8,
+    /// Gate of each step, repeated when the arpeggio has more steps.
+    pub gates: Vec<f64>,
+    /// Velocity of each step, repeated when the arpeggio has more steps.
+    pub velocities: Vec<u8>,
+    /// Notes in the order they were added.
+    notes: Vec<ChromaticNote>,
+    /// Sequence used by [`Arpeggiator::next_step`], so it isn't rebuilt for every step.
+    cached_sequence: Vec<ChromaticNote>,
+    /// Pattern and octaves of the cached sequence, `None` when the notes have changed.
+    cached_for: Option<(ArpeggioPattern, u8)>,
+    step: usize,
+    random: Random,
+}
+
+const DEFAULT_GATE: f64 = 0.5;
+const DEFAULT_VELOCITY: u8 = 100;
+const DEFAULT_SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+impl Default for Arpeggiator {
+    fn default() -> Self {
+        Arpeggiator::new(ArpeggioPattern::default())
+    }
+}
+
+impl Arpeggiator {
+    pub fn new(pattern: ArpeggioPattern) -> Arpeggiator {
+        Arpeggiator {
+            pattern,
+            octaves: 1,
+            gates: vec![DEFAULT_GATE],
+            velocities: vec![DEFAULT_VELOCITY],
+            notes: Vec::new(),
+            cached_sequence: Vec::new(),
+            cached_for: None,
+            step: 0,
+            random: Random::new(DEFAULT_SEED),
+        }
+    }
+
+    /// Seed the random generator used by [`ArpeggioPattern::Random`]. The same seed
+    /// produces the same arpeggio.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let mut arpeggiator = Arpeggiator::new(ArpeggioPattern::Random);
+    /// arpeggiator.set_chord(ChromaticChordType::Minor, ChromaticNote::new(ChromaticTone::A, 3));
+    /// arpeggiator.seed(42);
+    /// let first: Vec<ArpeggioStep> = arpeggiator.by_ref().take(16).collect();
+    /// arpeggiator.seed(42);
+    /// let second: Vec<ArpeggioStep> = arpeggiator.by_ref().take(16).collect();
+    /// assert_eq!(first, second);
+    /// assert!(first.iter().all(|step| arpeggiator.notes().contains(&step.note)));
+    /// ```
+    pub fn seed(&mut self, seed: u64) {
+        self.random = Random::new(seed);
+    }
+
+    /// Replace the notes of the arpeggio. The order is used by [`ArpeggioPattern::AsPlayed`].
+    pub fn set_notes(&mut self, notes: &[ChromaticNote]) {
+        self.notes = notes.to_vec();
+        self.cached_for = None;
+    }
+
+    /// Replace the notes of the arpeggio by the notes of a chord.
+    pub fn set_chord(&mut self, chord_type: ChromaticChordType, root_position: ChromaticNote) {
+        self.notes = chord_type.notes(root_position);
+        self.cached_for = None;
+    }
+
+    /// Add a note, for example when a key is pressed.
+    pub fn add_note(&mut self, note: ChromaticNote) {
+        if !self.notes.contains(&note) {
+            self.notes.push(note);
+            self.cached_for = None;
+        }
+    }
+
+    /// Remove a note, for example when a key is released.
+    pub fn remove_note(&mut self, note: ChromaticNote) {
+        self.notes.retain(|n| *n != note);
+        self.cached_for = None;
+    }
+
+    pub fn notes(&self) -> &[ChromaticNote] {
+        &self.notes
+    }
+
+    /// Restart the arpeggio at its first step.
+    pub fn reset(&mut self) {
+        self.step = 0;
+    }
+
+    /// Notes of one cycle of the arpeggio, including the octave span.
+    ///
+    /// The random pattern has no fixed order; it returns the notes from low to high.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let mut arpeggiator = Arpeggiator::new(ArpeggioPattern::AsPlayed);
+    /// arpeggiator.set_notes(&[
+    ///     ChromaticNote::new(ChromaticTone::G, 4),
+    ///     ChromaticNote::new(ChromaticTone::C, 4),
+    /// ]);
+    /// arpeggiator.octaves = 2;
+    /// assert_eq!(
+    ///     arpeggiator.sequence(),
+    ///     vec![
+    ///         ChromaticNote::new(ChromaticTone::G, 4),
+    ///         ChromaticNote::new(ChromaticTone::C, 4),
+    ///         ChromaticNote::new(ChromaticTone::G, 5),
+    ///         ChromaticNote::new(ChromaticTone::C, 5),
+    ///     ]
+    /// );
+    /// ```
+    pub fn sequence(&self) -> Vec<ChromaticNote> {
+        let mut notes = self.notes.clone();
+        if self.pattern != ArpeggioPattern::AsPlayed {
+            notes.sort_by_key(|note| i32::from(*note));
+        }
+        let notes: Vec<ChromaticNote> = (0..self.octaves.max(1) as NoteStep)
+            .flat_map(|octave| notes.iter().filter_map(move |n| n.checked_add(octave * 12)))
+            .collect();
+
+        match self.pattern {
+            ArpeggioPattern::Up | ArpeggioPattern::Random | ArpeggioPattern::AsPlayed => notes,
+            ArpeggioPattern::Down => notes.into_iter().rev().collect(),
+            ArpeggioPattern::UpDown => there_and_back(notes),
+            ArpeggioPattern::DownUp => there_and_back(notes.into_iter().rev().collect()),
+        }
+    }
+
+    /// Next step of the arpeggio, `None` when there are no notes.
+    ///
+    /// The sequence is only rebuilt when the notes, pattern or octaves have changed.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let mut arpeggiator = Arpeggiator::new(ArpeggioPattern::Up);
+    /// arpeggiator.set_chord(ChromaticChordType::Major, ChromaticNote::new(ChromaticTone::C, 4));
+    /// let note = |arpeggiator: &mut Arpeggiator| arpeggiator.next_step().unwrap().note;
+    /// assert_eq!(note(&mut arpeggiator), ChromaticNote::new(ChromaticTone::C, 4));
+    ///
+    /// arpeggiator.pattern = ArpeggioPattern::Down;
+    /// assert_eq!(note(&mut arpeggiator), ChromaticNote::new(ChromaticTone::E, 4));
+    ///
+    /// arpeggiator.octaves = 2;
+    /// assert_eq!(note(&mut arpeggiator), ChromaticNote::new(ChromaticTone::C, 5));
+    ///
+    /// // B5 G5 E5 C5 B4 G4 E4 C4
+    /// arpeggiator.add_note(ChromaticNote::new(ChromaticTone::B, 4));
+    /// assert_eq!(note(&mut arpeggiator), ChromaticNote::new(ChromaticTone::C, 5));
+    ///
+    /// arpeggiator.set_notes(&[]);
+    /// assert_eq!(arpeggiator.next_step(), None);
+    /// ```
+    pub fn next_step(&mut self) -> Option<ArpeggioStep> {
+        let cache_key = Some((self.pattern, self.octaves));
+        if self.cached_for != cache_key {
+            self.cached_sequence = self.sequence();
+            self.cached_for = cache_key;
+        }
+        let sequence = &self.cached_sequence;
+        if sequence.is_empty() {
+            return None;
+        }
+        let note = if self.pattern == ArpeggioPattern::Random {
+            sequence[self.random.below(sequence.len())]
+        } else {
+            sequence[self.step % sequence.len()]
+        };
+        let step = ArpeggioStep {
+            note,
+            velocity: repeat(&self.velocities, self.step).unwrap_or(DEFAULT_VELOCITY),
+            gate: repeat(&self.gates, self.step).unwrap_or(DEFAULT_GATE),
+        };
+        self.step += 1;
+        Some(step)
+    }
+}
+
+/// Arpeggiators never end, but return `None` when there are no notes.
+impl Iterator for Arpeggiator {
+    type Item = ArpeggioStep;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_step()
+    }
+}
+
+/// Append the notes in reverse order, without repeating the first and last note.
+fn there_and_back(mut notes: Vec<ChromaticNote>) -> Vec<ChromaticNote> {
+    if notes.len() > 2 {
+        let back: Vec<ChromaticNote> = notes[1..notes.len() - 1].iter().rev().copied().collect();
+        notes.extend(back);
+    }
+    notes
+}
+
+fn repeat<T: Copy>(pattern: &[T], step: usize) -> Option<T> {
+    (!pattern.is_empty()).then(|| pattern[step % pattern.len()])
+}
+
+/// Small xorshift random generator. Good enough to pick notes without depending on a random
+/// crate.
+#[derive(Debug, Clone)]
+struct Random {
+    state: u64,
+}
+
+impl Random {
+    fn new(seed: u64) -> Random {
+        // Xorshift gets stuck at zero.
+        Random {
+            state: if seed == 0 { DEFAULT_SEED } else { seed },
+        }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Random number below the given bound.
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
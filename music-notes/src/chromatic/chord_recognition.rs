@@ -0,0 +1,192 @@
+//! Chord recognition
+//!
+//! Find the chords that match a set of sounding notes. Every sounding tone is tried as the
+//! root of every chord type. Tones that are sounding but aren't part of the chord are
+//! extensions, chord tones that aren't sounding are omissions. Only the fifth of chords with a
+//! third and the ninth and eleventh below a higher extension can be omitted. Candidates are
+//! ranked by the number of extensions and omissions, chords in root position are preferred
+//! over inversions.
+use std::fmt::Display;
+
+use crate::{ChordSymbol, ChromaticChordType, ChromaticNote, ChromaticTone, Interval, NoteStep};
+
+const PENALTY_OMISSION: usize = 3;
+const PENALTY_EXTENSION: usize = 2;
+const PENALTY_INVERSION: usize = 1;
+
+/// Chord that matches a set of sounding notes.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let notes = [
+///     ChromaticNote::new(ChromaticTone::E, 3),
+///     ChromaticNote::new(ChromaticTone::G, 3),
+///     ChromaticNote::new(ChromaticTone::B, 3),
+///     ChromaticNote::new(ChromaticTone::C, 4),
+/// ];
+/// let chord = recognize_chord(&notes).unwrap();
+/// assert_eq!(chord.root, ChromaticTone::C);
+/// assert_eq!(chord.chord_type, ChromaticChordType::MajorSeventh);
+/// assert_eq!(chord.inversion(), Some(1));
+/// assert_eq!(chord.to_string(), "Cmaj7/E");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecognizedChord {
+    pub root: ChromaticTone,
+    pub chord_type: ChromaticChordType,
+    /// Tone of the lowest sounding note.
+    pub bass: ChromaticTone,
+    /// Sounding tones that aren't part of the chord type.
+    pub extensions: Vec<Interval>,
+    /// Tones of the chord type that aren't sounding.
+    pub omissions: Vec<Interval>,
+}
+
+impl RecognizedChord {
+    /// Inversion of the chord: 0 is root position, 1 is first inversion (third in the bass),
+    /// 2 is second inversion (fifth in the bass), etc.
+    ///
+    /// Returns `None` when the bass isn't a tone of the chord type.
+    pub fn inversion(&self) -> Option<usize> {
+        let bass_steps = steps_between(self.root, self.bass);
+        self.chord_type
+            .note_steps()
+            .iter()
+            .position(|step| step.rem_euclid(12) == bass_steps)
+    }
+
+    fn penalty(&self) -> usize {
+        let inversion_penalty = if self.root == self.bass {
+            0
+        } else {
+            PENALTY_INVERSION
+        };
+        self.omissions.len() * PENALTY_OMISSION
+            + self.extensions.len() * PENALTY_EXTENSION
+            + inversion_penalty
+    }
+}
+
+/// Find all chords matching the given notes, best match first.
+///
+/// The order of the notes doesn't matter; the lowest note is used as the bass.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let notes = [
+///     ChromaticNote::new(ChromaticTone::A, 3),
+///     ChromaticNote::new(ChromaticTone::C, 4),
+///     ChromaticNote::new(ChromaticTone::E, 4),
+///     ChromaticNote::new(ChromaticTone::G, 4),
+/// ];
+/// let chords: Vec<String> = recognize_chords(&notes)
+///     .iter()
+///     .take(2)
+///     .map(|chord| chord.to_string())
+///     .collect();
+/// assert_eq!(chords, vec!["Am7", "C6/A"]);
+/// ```
+pub fn recognize_chords(notes: &[ChromaticNote]) -> Vec<RecognizedChord> {
+    let Some(bass) = notes.iter().min_by_key(|note| i32::from(**note)) else {
+        return Vec::new();
+    };
+    let sounding = notes
+        .iter()
+        .fold(0_u16, |mask, note| mask | (1 << u8::from(note.tone)));
+
+    let mut candidates = Vec::new();
+    for root in (0..12_u8).filter(|tone| sounding & (1 << tone) != 0) {
+        let root = ChromaticTone::from(root);
+        for (index, chord_type) in ChromaticChordType::ALL.iter().enumerate() {
+            if let Some(chord) = match_chord(root, *chord_type, bass.tone, sounding) {
+                candidates.push((chord.penalty(), index, chord));
+            }
+        }
+    }
+    candidates.sort_by_key(|(penalty, index, _chord)| (*penalty, *index));
+    candidates.into_iter().map(|(_, _, chord)| chord).collect()
+}
+
+/// Find the chord that matches the given notes best.
+///
+/// Returns `None` when no chord matches, for example when less than two different tones
+/// are sounding.
+pub fn recognize_chord(notes: &[ChromaticNote]) -> Option<RecognizedChord> {
+    recognize_chords(notes).into_iter().next()
+}
+
+fn match_chord(
+    root: ChromaticTone,
+    chord_type: ChromaticChordType,
+    bass: ChromaticTone,
+    sounding: u16,
+) -> Option<RecognizedChord> {
+    let intervals = chord_type.intervals();
+    let highest_number = intervals.iter().map(Interval::number).max().unwrap_or(1);
+    let has_major_third = intervals.contains(&Interval::MAJOR_THIRD);
+    let has_third = has_major_third || intervals.contains(&Interval::MINOR_THIRD);
+    let is_sounding = |steps: NoteStep| {
+        let tone = (u8::from(root) as NoteStep + steps).rem_euclid(12);
+        sounding & (1 << tone) != 0
+    };
+
+    let omissions: Vec<Interval> = intervals
+        .iter()
+        .filter(|interval| !is_sounding(interval.semitones()))
+        .copied()
+        .collect();
+    let can_omit = |interval: &Interval| {
+        (has_third && *interval == Interval::PERFECT_FIFTH)
+            || ([Interval::MAJOR_NINTH, Interval::PERFECT_ELEVENTH].contains(interval)
+                && interval.number() < highest_number)
+    };
+    if !omissions.iter().all(can_omit) {
+        return None;
+    }
+
+    let chord_steps: Vec<NoteStep> = intervals
+        .iter()
+        .map(|interval| interval.semitones().rem_euclid(12))
+        .collect();
+    let extensions = (0..12)
+        .filter(|steps| is_sounding(*steps) && !chord_steps.contains(steps))
+        .map(|steps| extension_interval(steps, has_major_third))
+        .collect();
+
+    Some(RecognizedChord {
+        root,
+        chord_type,
+        bass,
+        extensions,
+        omissions,
+    })
+}
+
+/// Interval of a tone that is added to a chord, extensions above the octave are preferred.
+fn extension_interval(steps: NoteStep, has_major_third: bool) -> Interval {
+    match steps {
+        1 => Interval::MINOR_NINTH,
+        2 => Interval::MAJOR_NINTH,
+        3 if has_major_third => Interval::AUGMENTED_NINTH,
+        5 => Interval::PERFECT_ELEVENTH,
+        6 => Interval::AUGMENTED_ELEVENTH,
+        8 => Interval::MINOR_THIRTEENTH,
+        9 => Interval::MAJOR_THIRTEENTH,
+        steps => Interval::from_semitones(steps),
+    }
+}
+
+fn steps_between(from: ChromaticTone, to: ChromaticTone) -> NoteStep {
+    (u8::from(to) as NoteStep - u8::from(from) as NoteStep).rem_euclid(12)
+}
+
+/// Chord symbol, for example `Cmaj7/E`, `Ebadd9` or `G7(no5)`.
+impl Display for RecognizedChord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", ChordSymbol::from(self))
+    }
+}
@@ -0,0 +1,405 @@
+//! Chord symbols
+//!
+//! Lead sheet notation of chords, for example `Cm7b5`, `F#sus4/C#` or `Bbmaj9`. A symbol
+//! starts with the root, followed by the chord type, alterations (`b9`, `#11`), added tones
+//! (`add9`), omitted tones (`no3`, `omit5`) and an optional bass after a slash.
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::{
+    parse_spelled_tone, ChromaticChordType, ChromaticNote, ChromaticTone, Interval,
+    IntervalQuality, NoteStep, Octave, ParseNoteError, RecognizedChord, SpelledTone,
+};
+
+/// Symbols of the chord types. Parsing uses the longest matching symbol.
+const CHORD_TYPE_SYMBOLS: [(&str, ChromaticChordType); 52] = [
+    ("5", ChromaticChordType::Power),
+    ("maj", ChromaticChordType::Major),
+    ("M", ChromaticChordType::Major),
+    ("m", ChromaticChordType::Minor),
+    ("min", ChromaticChordType::Minor),
+    ("-", ChromaticChordType::Minor),
+    ("dim", ChromaticChordType::Diminished),
+    ("°", ChromaticChordType::Diminished),
+    ("aug", ChromaticChordType::Augmented),
+    ("+", ChromaticChordType::Augmented),
+    ("sus2", ChromaticChordType::Sus2),
+    ("sus4", ChromaticChordType::Sus4),
+    ("sus", ChromaticChordType::Sus4),
+    ("7sus2", ChromaticChordType::SevenSus2),
+    ("7sus4", ChromaticChordType::SevenSus4),
+    ("7sus", ChromaticChordType::SevenSus4),
+    ("6", ChromaticChordType::Sixth),
+    ("m6", ChromaticChordType::MinorSixth),
+    ("min6", ChromaticChordType::MinorSixth),
+    ("-6", ChromaticChordType::MinorSixth),
+    ("7", ChromaticChordType::Seventh),
+    ("maj7", ChromaticChordType::MajorSeventh),
+    ("M7", ChromaticChordType::MajorSeventh),
+    ("Δ", ChromaticChordType::MajorSeventh),
+    ("Δ7", ChromaticChordType::MajorSeventh),
+    ("m7", ChromaticChordType::MinorSeventh),
+    ("min7", ChromaticChordType::MinorSeventh),
+    ("-7", ChromaticChordType::MinorSeventh),
+    ("m7b5", ChromaticChordType::HalfDiminished),
+    ("ø", ChromaticChordType::HalfDiminished),
+    ("ø7", ChromaticChordType::HalfDiminished),
+    ("dim7", ChromaticChordType::DiminishedSeventh),
+    ("°7", ChromaticChordType::DiminishedSeventh),
+    ("mMaj7", ChromaticChordType::MinorMajorSeventh),
+    ("mM7", ChromaticChordType::MinorMajorSeventh),
+    ("m(maj7)", ChromaticChordType::MinorMajorSeventh),
+    ("aug7", ChromaticChordType::AugmentedSeventh),
+    ("+7", ChromaticChordType::AugmentedSeventh),
+    ("6/9", ChromaticChordType::SixNine),
+    ("69", ChromaticChordType::SixNine),
+    ("9", ChromaticChordType::Ninth),
+    ("maj9", ChromaticChordType::MajorNinth),
+    ("M9", ChromaticChordType::MajorNinth),
+    ("m9", ChromaticChordType::MinorNinth),
+    ("11", ChromaticChordType::Eleventh),
+    ("maj11", ChromaticChordType::MajorEleventh),
+    ("M11", ChromaticChordType::MajorEleventh),
+    ("m11", ChromaticChordType::MinorEleventh),
+    ("13", ChromaticChordType::Thirteenth),
+    ("maj13", ChromaticChordType::MajorThirteenth),
+    ("M13", ChromaticChordType::MajorThirteenth),
+    ("m13", ChromaticChordType::MinorThirteenth),
+];
+
+/// Chord written as a lead sheet chord symbol.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let chord: ChordSymbol = "F#sus4/C#".parse().unwrap();
+/// assert_eq!(chord.chord_type, ChromaticChordType::Sus4);
+/// assert_eq!(chord.bass.unwrap().to_string(), "C#");
+///
+/// let chord: ChordSymbol = "C7(b9, #11)".parse().unwrap();
+/// assert_eq!(chord.alterations, vec![Interval::MINOR_NINTH, Interval::AUGMENTED_ELEVENTH]);
+/// assert_eq!(chord.to_string(), "C7(b9,#11)");
+///
+/// let chord: ChordSymbol = "Bbmaj9".parse().unwrap();
+/// let tones: Vec<String> = chord.tones().iter().map(|tone| tone.to_string()).collect();
+/// assert_eq!(tones, vec!["Bb", "D", "F", "A", "C"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChordSymbol {
+    pub root: SpelledTone,
+    pub chord_type: ChromaticChordType,
+    /// Altered chord tones. An alteration replaces the chord tone with the same number, or is
+    /// added when the chord type doesn't have a tone with that number.
+    pub alterations: Vec<Interval>,
+    /// Tones added to the chord type.
+    pub additions: Vec<Interval>,
+    /// Numbers of the chord tones that are left out, 5 is the fifth.
+    pub omissions: Vec<u8>,
+    /// Bass tone when it isn't the root.
+    pub bass: Option<SpelledTone>,
+}
+
+impl ChordSymbol {
+    pub fn new(root: SpelledTone, chord_type: ChromaticChordType) -> Self {
+        Self {
+            root,
+            chord_type,
+            alterations: Vec::new(),
+            additions: Vec::new(),
+            omissions: Vec::new(),
+            bass: None,
+        }
+    }
+
+    /// Intervals of the chord tones above the root, in ascending order.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let chord: ChordSymbol = "C7b5(no3)".parse().unwrap();
+    /// assert_eq!(
+    ///     chord.intervals(),
+    ///     vec![Interval::PERFECT_UNISON, Interval::DIMINISHED_FIFTH, Interval::MINOR_SEVENTH]
+    /// );
+    ///
+    /// // Alterations with the same number are all kept.
+    /// let chord: ChordSymbol = "C7b9#9".parse().unwrap();
+    /// let semitones: Vec<NoteStep> = chord.intervals().iter().map(Interval::semitones).collect();
+    /// assert_eq!(semitones, vec![0, 4, 7, 10, 13, 15]);
+    /// ```
+    pub fn intervals(&self) -> Vec<Interval> {
+        let mut intervals = self.chord_type.intervals();
+        // Alterations replace the unaltered chord tone, not each other.
+        intervals.retain(|interval| {
+            !self
+                .alterations
+                .iter()
+                .any(|alteration| alteration.number() == interval.number())
+        });
+        intervals.extend(self.alterations.iter().copied());
+        intervals.extend(self.additions.iter().copied());
+        intervals.retain(|interval| !self.omissions.contains(&interval.number()));
+        intervals.sort_by_key(|interval| (interval.semitones(), interval.number()));
+        intervals.dedup();
+        intervals
+    }
+
+    /// Spelled tones of the chord starting with the root. The bass isn't included.
+    pub fn tones(&self) -> Vec<SpelledTone> {
+        self.intervals()
+            .iter()
+            .map(|interval| self.root.above_or_sharp(*interval))
+            .collect()
+    }
+
+    /// Notes of the chord with the root in the given octave, in ascending order. The bass is
+    /// placed below the root.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let chord: ChordSymbol = "Am/G".parse().unwrap();
+    /// let notes: Vec<String> = chord.notes(3).iter().map(|note| note.to_string()).collect();
+    /// assert_eq!(notes, vec!["G3", "A3", "C4", "E4"]);
+    /// ```
+    pub fn notes(&self, octave: Octave) -> Vec<ChromaticNote> {
+        let root_position = ChromaticNote::new(ChromaticTone::from(self.root), octave);
+        let mut notes: Vec<ChromaticNote> = self
+            .intervals()
+            .iter()
+            .map(|interval| root_position + *interval)
+            .collect();
+        if let Some(bass) = self.bass {
+            let steps_below = (u8::from(root_position.tone) as NoteStep
+                - u8::from(ChromaticTone::from(bass)) as NoteStep)
+                .rem_euclid(12);
+            if steps_below != 0 {
+                notes.insert(0, root_position + -steps_below);
+            }
+        }
+        notes
+    }
+}
+
+/// Convert a recognized chord to a chord symbol. Extensions become added tones.
+impl From<&RecognizedChord> for ChordSymbol {
+    fn from(chord: &RecognizedChord) -> Self {
+        let root = spell_root(chord.root);
+        let bass = (chord.bass != chord.root).then(|| {
+            chord
+                .inversion()
+                .and_then(|index| chord.chord_type.spell(root).get(index).copied())
+                .unwrap_or_else(|| spell_root(chord.bass))
+        });
+        Self {
+            root,
+            chord_type: chord.chord_type,
+            alterations: Vec::new(),
+            additions: chord.extensions.clone(),
+            omissions: chord
+                .omissions
+                .iter()
+                .map(|interval| interval.number())
+                .collect(),
+            bass,
+        }
+    }
+}
+
+/// Spelling of a root when no key is known. Uses the most common names of the black keys.
+fn spell_root(tone: ChromaticTone) -> SpelledTone {
+    match tone {
+        ChromaticTone::DSharp | ChromaticTone::GSharp | ChromaticTone::ASharp => {
+            SpelledTone::flat(tone)
+        }
+        _ => SpelledTone::sharp(tone),
+    }
+}
+
+/// Alteration and number of a chord tone, for example `b9` or `#11`.
+fn degree_label(interval: &Interval) -> String {
+    let alteration = match interval.quality() {
+        IntervalQuality::Minor | IntervalQuality::Diminished(_) => "b",
+        IntervalQuality::Major | IntervalQuality::Perfect => "",
+        IntervalQuality::Augmented(_) => "#",
+    };
+    format!("{alteration}{}", interval.number())
+}
+
+/// Label of an added tone. Sevenths are written as in the chord types.
+fn addition_label(interval: &Interval) -> String {
+    match *interval {
+        Interval::MINOR_SEVENTH => String::from("7"),
+        Interval::MAJOR_SEVENTH => String::from("maj7"),
+        _ => degree_label(interval),
+    }
+}
+
+/// Read a degree like `9`, `b13` or `#11`.
+fn parse_degree(text: &str) -> Option<(Interval, &str)> {
+    let (alteration, rest) = match text.chars().next()? {
+        'b' | '♭' => (-1, &text[text.chars().next()?.len_utf8()..]),
+        '#' | '♯' => (1, &text[text.chars().next()?.len_utf8()..]),
+        _ => (0, text),
+    };
+    let (number, rest) = parse_number(rest)?;
+    let is_perfect = matches!((number.max(1) - 1) % 7, 0 | 3 | 4);
+    let quality = match (alteration, is_perfect) {
+        (-1, true) => IntervalQuality::Diminished(1),
+        (-1, false) => IntervalQuality::Minor,
+        (0, true) => IntervalQuality::Perfect,
+        (0, false) => IntervalQuality::Major,
+        _ => IntervalQuality::Augmented(1),
+    };
+    Some((Interval::new(quality, number)?, rest))
+}
+
+fn parse_number(text: &str) -> Option<(u8, &str)> {
+    let end = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    let number = text[..end].parse().ok()?;
+    Some((number, &text[end..]))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseChordError {
+    /// The chord symbol doesn't start with a valid root.
+    InvalidRoot(ParseNoteError),
+    /// The text after the chord type isn't an alteration, added or omitted tone.
+    InvalidModifier(String),
+    /// The text after the slash isn't a valid bass tone.
+    InvalidBass(String),
+}
+
+impl Display for ParseChordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidRoot(error) => write!(f, "invalid root: {error}"),
+            Self::InvalidModifier(text) => write!(f, "`{text}` isn't a valid chord modifier"),
+            Self::InvalidBass(text) => write!(f, "`{text}` isn't a valid bass tone"),
+        }
+    }
+}
+
+impl Error for ParseChordError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidRoot(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for ChordSymbol {
+    type Err = ParseChordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        let (root, rest) = parse_spelled_tone(text).map_err(ParseChordError::InvalidRoot)?;
+
+        // The slash of `6/9` isn't a bass, so only split when a tone follows the slash.
+        let (rest, bass) = match rest.rsplit_once('/') {
+            Some((chord, bass)) => match parse_spelled_tone(bass) {
+                Ok((tone, "")) => (chord, Some(tone)),
+                Ok(_) => return Err(ParseChordError::InvalidBass(bass.to_string())),
+                Err(_) => (rest, None),
+            },
+            None => (rest, None),
+        };
+
+        let (chord_type, mut rest) = CHORD_TYPE_SYMBOLS
+            .iter()
+            .filter(|(symbol, _)| rest.starts_with(symbol))
+            .max_by_key(|(symbol, _)| symbol.len())
+            .map(|(symbol, chord_type)| (*chord_type, &rest[symbol.len()..]))
+            .unwrap_or((ChromaticChordType::Major, rest));
+
+        let mut chord = ChordSymbol::new(root, chord_type);
+        chord.bass = bass.filter(|bass| *bass != root);
+        loop {
+            rest = rest.trim_start_matches(['(', ')', ',', ' ']);
+            if rest.is_empty() {
+                break;
+            }
+            let invalid = || ParseChordError::InvalidModifier(rest.to_string());
+            if let Some(degree) = rest.strip_prefix("add") {
+                let (interval, remaining) = parse_degree(degree).ok_or_else(invalid)?;
+                chord.additions.push(interval);
+                rest = remaining;
+            } else if let Some(number) = rest.strip_prefix("no").or(rest.strip_prefix("omit")) {
+                let (number, remaining) = parse_number(number).ok_or_else(invalid)?;
+                chord.omissions.push(number);
+                rest = remaining;
+            } else if rest.starts_with(['b', '#', '♭', '♯']) {
+                let (interval, remaining) = parse_degree(rest).ok_or_else(invalid)?;
+                chord.alterations.push(interval);
+                rest = remaining;
+            } else {
+                return Err(invalid());
+            }
+        }
+        Ok(chord)
+    }
+}
+
+/// Chord symbol, for example `Cmaj7/E`, `Ebadd9` or `G7(no5)`.
+///
+/// Alterations are put between parentheses when the chord type is written without a symbol
+/// or its symbol ends with a number, so `C(b9)` isn't read as a C flat chord and `Cm7(b5)`
+/// isn't read as a half diminished chord.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let alterations = [
+///     Interval::DIMINISHED_FIFTH,
+///     Interval::AUGMENTED_FIFTH,
+///     Interval::MINOR_NINTH,
+///     Interval::AUGMENTED_NINTH,
+///     Interval::AUGMENTED_ELEVENTH,
+///     Interval::MINOR_THIRTEENTH,
+/// ];
+/// for root in ["C", "C#", "Db", "B"] {
+///     let root = root.parse::<ChordSymbol>().unwrap().root;
+///     for chord_type in ChromaticChordType::ALL {
+///         for alteration in alterations {
+///             let mut chord = ChordSymbol::new(root, chord_type);
+///             chord.alterations.push(alteration);
+///             assert_eq!(chord.to_string().parse(), Ok(chord));
+///         }
+///     }
+/// }
+///
+/// let mut chord: ChordSymbol = "C".parse().unwrap();
+/// chord.alterations = vec![Interval::MINOR_NINTH, Interval::AUGMENTED_ELEVENTH];
+/// assert_eq!(chord.to_string(), "C(b9,#11)");
+/// chord.chord_type = ChromaticChordType::Minor;
+/// assert_eq!(chord.to_string(), "Cmb9#11");
+/// ```
+impl Display for ChordSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = self.chord_type.to_string();
+        write!(f, "{}{symbol}", self.root)?;
+        let alterations: Vec<String> = self.alterations.iter().map(degree_label).collect();
+        if symbol.is_empty() || symbol.ends_with(|c: char| c.is_ascii_digit()) {
+            if !alterations.is_empty() {
+                write!(f, "({})", alterations.join(","))?;
+            }
+        } else {
+            write!(f, "{}", alterations.concat())?;
+        }
+        for addition in &self.additions {
+            write!(f, "add{}", addition_label(addition))?;
+        }
+        for omission in &self.omissions {
+            write!(f, "(no{omission})")?;
+        }
+        if let Some(bass) = self.bass {
+            write!(f, "/{bass}")?;
+        }
+        Ok(())
+    }
+}
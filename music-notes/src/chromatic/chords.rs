@@ -1,5 +1,9 @@
-use crate::{ChromaticNote, NoteStep};
+use std::fmt::Display;
 
+use crate::{ChromaticNote, Interval, NoteStep};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ChromaticChordType {
     Major,
     Minor,
@@ -19,42 +23,324 @@ pub enum ChromaticChordType {
     MinorSeventh,
     MinorNinth,
     MinorEleventh,
+    /// Root and fifth without a third.
+    Power,
+    DiminishedSeventh,
+    /// Minor seventh chord with a diminished fifth (m7b5).
+    HalfDiminished,
+    MinorMajorSeventh,
+    AugmentedSeventh,
+    SixNine,
+    Eleventh,
+    Thirteenth,
+    MajorThirteenth,
+    MinorThirteenth,
 }
 
 impl ChromaticChordType {
-    pub fn note_steps(&self) -> Vec<NoteStep> {
+    /// All chord types, from simple to complex.
+    pub const ALL: [ChromaticChordType; 28] = [
+        Self::Power,
+        Self::Major,
+        Self::Minor,
+        Self::Diminished,
+        Self::Augmented,
+        Self::Sus2,
+        Self::Sus4,
+        Self::Sixth,
+        Self::MinorSixth,
+        Self::Seventh,
+        Self::MajorSeventh,
+        Self::MinorSeventh,
+        Self::HalfDiminished,
+        Self::DiminishedSeventh,
+        Self::MinorMajorSeventh,
+        Self::AugmentedSeventh,
+        Self::SevenSus2,
+        Self::SevenSus4,
+        Self::SixNine,
+        Self::Ninth,
+        Self::MajorNinth,
+        Self::MinorNinth,
+        Self::Eleventh,
+        Self::MajorEleventh,
+        Self::MinorEleventh,
+        Self::Thirteenth,
+        Self::MajorThirteenth,
+        Self::MinorThirteenth,
+    ];
+
+    /// Symbol of the chord type that is written after the root, for example `m7` in `Am7`.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    /// assert_eq!(ChromaticChordType::Major.symbol(), "");
+    /// assert_eq!(ChromaticChordType::MajorSeventh.symbol(), "maj7");
+    /// ```
+    pub fn symbol(&self) -> &'static str {
         match self {
-            Self::Major => vec![0, 4, 7],
-            Self::Minor => vec![0, 3, 7],
-            Self::Diminished => vec![0, 3, 6],
-            Self::Augmented => vec![0, 4, 8],
-            Self::Sus2 => vec![0, 2, 7],
-            Self::Sus4 => vec![0, 5, 7],
-            Self::SevenSus2 => vec![0, 2, 7, 10],
-            Self::SevenSus4 => vec![0, 5, 7, 10],
-            Self::Sixth => vec![0, 4, 7, 9],
-            Self::Seventh => vec![0, 4, 7, 10],
-            Self::Ninth => vec![0, 4, 7, 10, 14],
-            Self::MajorSeventh => vec![0, 4, 7, 11],
-            Self::MajorNinth => vec![0, 4, 7, 11, 14],
-            Self::MajorEleventh => vec![0, 4, 7, 11, 14, 17],
-            Self::MinorSixth => vec![0, 3, 7, 9],
-            Self::MinorSeventh => vec![0, 3, 7, 10],
-            Self::MinorNinth => vec![0, 2, 3, 7, 10],
-            Self::MinorEleventh => vec![0, 3, 7, 10, 14, 17],
+            Self::Major => "",
+            Self::Minor => "m",
+            Self::Diminished => "dim",
+            Self::Augmented => "aug",
+            Self::Sus2 => "sus2",
+            Self::Sus4 => "sus4",
+            Self::SevenSus2 => "7sus2",
+            Self::SevenSus4 => "7sus4",
+            Self::Sixth => "6",
+            Self::Seventh => "7",
+            Self::Ninth => "9",
+            Self::MajorSeventh => "maj7",
+            Self::MajorNinth => "maj9",
+            Self::MajorEleventh => "maj11",
+            Self::MinorSixth => "m6",
+            Self::MinorSeventh => "m7",
+            Self::MinorNinth => "m9",
+            Self::MinorEleventh => "m11",
+            Self::Power => "5",
+            Self::DiminishedSeventh => "dim7",
+            Self::HalfDiminished => "m7b5",
+            Self::MinorMajorSeventh => "mMaj7",
+            Self::AugmentedSeventh => "aug7",
+            // Written without a slash so it can't be mistaken for a bass or secondary chord.
+            Self::SixNine => "69",
+            Self::Eleventh => "11",
+            Self::Thirteenth => "13",
+            Self::MajorThirteenth => "maj13",
+            Self::MinorThirteenth => "m13",
         }
     }
 
+    /// Intervals of the chord tones above the root.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    /// assert_eq!(
+    ///     ChromaticChordType::Diminished.intervals(),
+    ///     vec![Interval::PERFECT_UNISON, Interval::MINOR_THIRD, Interval::DIMINISHED_FIFTH]
+    /// );
+    /// ```
+    pub fn intervals(&self) -> Vec<Interval> {
+        use Interval as I;
+        match self {
+            Self::Major => vec![I::PERFECT_UNISON, I::MAJOR_THIRD, I::PERFECT_FIFTH],
+            Self::Minor => vec![I::PERFECT_UNISON, I::MINOR_THIRD, I::PERFECT_FIFTH],
+            Self::Diminished => vec![I::PERFECT_UNISON, I::MINOR_THIRD, I::DIMINISHED_FIFTH],
+            Self::Augmented => vec![I::PERFECT_UNISON, I::MAJOR_THIRD, I::AUGMENTED_FIFTH],
+            Self::Sus2 => vec![I::PERFECT_UNISON, I::MAJOR_SECOND, I::PERFECT_FIFTH],
+            Self::Sus4 => vec![I::PERFECT_UNISON, I::PERFECT_FOURTH, I::PERFECT_FIFTH],
+            Self::SevenSus2 => vec![
+                I::PERFECT_UNISON,
+                I::MAJOR_SECOND,
+                I::PERFECT_FIFTH,
+                I::MINOR_SEVENTH,
+            ],
+            Self::SevenSus4 => vec![
+                I::PERFECT_UNISON,
+                I::PERFECT_FOURTH,
+                I::PERFECT_FIFTH,
+                I::MINOR_SEVENTH,
+            ],
+            Self::Sixth => vec![
+                I::PERFECT_UNISON,
+                I::MAJOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MAJOR_SIXTH,
+            ],
+            Self::Seventh => vec![
+                I::PERFECT_UNISON,
+                I::MAJOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MINOR_SEVENTH,
+            ],
+            Self::Ninth => vec![
+                I::PERFECT_UNISON,
+                I::MAJOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MINOR_SEVENTH,
+                I::MAJOR_NINTH,
+            ],
+            Self::MajorSeventh => vec![
+                I::PERFECT_UNISON,
+                I::MAJOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MAJOR_SEVENTH,
+            ],
+            Self::MajorNinth => vec![
+                I::PERFECT_UNISON,
+                I::MAJOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MAJOR_SEVENTH,
+                I::MAJOR_NINTH,
+            ],
+            Self::MajorEleventh => vec![
+                I::PERFECT_UNISON,
+                I::MAJOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MAJOR_SEVENTH,
+                I::MAJOR_NINTH,
+                I::PERFECT_ELEVENTH,
+            ],
+            Self::MinorSixth => vec![
+                I::PERFECT_UNISON,
+                I::MINOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MAJOR_SIXTH,
+            ],
+            Self::MinorSeventh => vec![
+                I::PERFECT_UNISON,
+                I::MINOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MINOR_SEVENTH,
+            ],
+            Self::MinorNinth => vec![
+                I::PERFECT_UNISON,
+                I::MINOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MINOR_SEVENTH,
+                I::MAJOR_NINTH,
+            ],
+            Self::MinorEleventh => vec![
+                I::PERFECT_UNISON,
+                I::MINOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MINOR_SEVENTH,
+                I::MAJOR_NINTH,
+                I::PERFECT_ELEVENTH,
+            ],
+            Self::Power => vec![I::PERFECT_UNISON, I::PERFECT_FIFTH],
+            Self::DiminishedSeventh => vec![
+                I::PERFECT_UNISON,
+                I::MINOR_THIRD,
+                I::DIMINISHED_FIFTH,
+                I::DIMINISHED_SEVENTH,
+            ],
+            Self::HalfDiminished => vec![
+                I::PERFECT_UNISON,
+                I::MINOR_THIRD,
+                I::DIMINISHED_FIFTH,
+                I::MINOR_SEVENTH,
+            ],
+            Self::MinorMajorSeventh => vec![
+                I::PERFECT_UNISON,
+                I::MINOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MAJOR_SEVENTH,
+            ],
+            Self::AugmentedSeventh => vec![
+                I::PERFECT_UNISON,
+                I::MAJOR_THIRD,
+                I::AUGMENTED_FIFTH,
+                I::MINOR_SEVENTH,
+            ],
+            Self::SixNine => vec![
+                I::PERFECT_UNISON,
+                I::MAJOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MAJOR_SIXTH,
+                I::MAJOR_NINTH,
+            ],
+            Self::Eleventh => vec![
+                I::PERFECT_UNISON,
+                I::MAJOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MINOR_SEVENTH,
+                I::MAJOR_NINTH,
+                I::PERFECT_ELEVENTH,
+            ],
+            // The eleventh clashes with the major third and is left out.
+            Self::Thirteenth => vec![
+                I::PERFECT_UNISON,
+                I::MAJOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MINOR_SEVENTH,
+                I::MAJOR_NINTH,
+                I::MAJOR_THIRTEENTH,
+            ],
+            Self::MajorThirteenth => vec![
+                I::PERFECT_UNISON,
+                I::MAJOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MAJOR_SEVENTH,
+                I::MAJOR_NINTH,
+                I::MAJOR_THIRTEENTH,
+            ],
+            Self::MinorThirteenth => vec![
+                I::PERFECT_UNISON,
+                I::MINOR_THIRD,
+                I::PERFECT_FIFTH,
+                I::MINOR_SEVENTH,
+                I::MAJOR_NINTH,
+                I::PERFECT_ELEVENTH,
+                I::MAJOR_THIRTEENTH,
+            ],
+        }
+    }
+
+    /// Number of semitones of the chord tones above the root.
+    pub fn note_steps(&self) -> Vec<NoteStep> {
+        self.intervals().iter().map(Interval::semitones).collect()
+    }
+
     /// ```
     /// use music_notes::*;
     /// let notes = ChromaticChordType::Major.notes(ChromaticNote::new(ChromaticTone::C, 4));
     /// assert_eq!(notes, vec![ChromaticNote::new(ChromaticTone::C, 4), ChromaticNote::new(ChromaticTone::E, 4), ChromaticNote::new(ChromaticTone::G, 4)]);
     /// ```
     pub fn notes(&self, root_position: ChromaticNote) -> Vec<ChromaticNote> {
-        // TODO: add inversion.
         self.note_steps()
             .iter()
             .map(|s| root_position + *s)
             .collect()
     }
+
+    /// Notes of an inversion of the chord, in ascending order.
+    ///
+    /// Inversion 0 is the root position, inversion 1 has the second chord tone (usually the
+    /// third) in the bass, etc. The bass is the chord tone of the root position; notes below
+    /// it are moved up by octaves.
+    ///
+    /// # Panics
+    ///
+    /// When `inversion` isn't smaller than the number of chord tones.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    /// let c4 = ChromaticNote::new(ChromaticTone::C, 4);
+    /// let notes = ChromaticChordType::Major.inversion(c4, 2);
+    /// assert_eq!(notes, vec![
+    ///     ChromaticNote::new(ChromaticTone::G, 4),
+    ///     ChromaticNote::new(ChromaticTone::C, 5),
+    ///     ChromaticNote::new(ChromaticTone::E, 5),
+    /// ]);
+    /// ```
+    pub fn inversion(&self, root_position: ChromaticNote, inversion: usize) -> Vec<ChromaticNote> {
+        let note_steps = self.note_steps();
+        assert!(
+            inversion < note_steps.len(),
+            "chord doesn't have an inversion {inversion}"
+        );
+        let bass = note_steps[inversion];
+        let mut steps: Vec<NoteStep> = note_steps
+            .iter()
+            .map(|step| {
+                if *step < bass {
+                    step + (bass - step + 11) / 12 * 12
+                } else {
+                    *step
+                }
+            })
+            .collect();
+        steps.sort();
+        steps.iter().map(|step| root_position + *step).collect()
+    }
+}
+
+impl Display for ChromaticChordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
 }
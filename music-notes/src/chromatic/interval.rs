@@ -0,0 +1,317 @@
+//! Interval
+//!
+//! An interval is the distance between two notes. It is named by its number (the number of
+//! letter names it spans: unison, second, third, ...) and its quality (perfect, major, minor,
+//! augmented, diminished). Intervals larger than an octave are compound intervals.
+use std::{
+    fmt::Display,
+    ops::{Add, Sub},
+};
+
+use crate::{ChromaticNote, NoteStep};
+
+/// Number of semitones of the major or perfect interval of each simple interval number.
+const REFERENCE_SEMITONES: [NoteStep; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// Interval number of each number of semitones inside an octave.
+const NUMBER_BY_SEMITONES: [u8; 12] = [1, 2, 2, 3, 3, 4, 4, 5, 6, 6, 7, 7];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IntervalQuality {
+    /// Diminished the given number of times. `Diminished(2)` is doubly diminished.
+    Diminished(u8),
+    Minor,
+    Perfect,
+    Major,
+    /// Augmented the given number of times. `Augmented(2)` is doubly augmented.
+    Augmented(u8),
+}
+
+/// Interval between two notes.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let c4 = ChromaticNote::new(ChromaticTone::C, 4);
+/// let g4 = ChromaticNote::new(ChromaticTone::G, 4);
+/// assert_eq!(g4 - c4, Interval::PERFECT_FIFTH);
+/// assert_eq!(c4 + Interval::PERFECT_FIFTH, g4);
+/// assert_eq!(Interval::PERFECT_FIFTH.inversion(), Interval::PERFECT_FOURTH);
+/// assert_eq!(Interval::MAJOR_THIRD + Interval::MINOR_THIRD, Interval::PERFECT_FIFTH);
+/// assert_eq!(Interval::MAJOR_NINTH.to_string(), "M9");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Interval {
+    /// Interval number, 1 is a unison, 8 is an octave.
+    number: u8,
+    semitones: NoteStep,
+}
+
+impl Interval {
+    pub const PERFECT_UNISON: Interval = Interval::from_parts(1, 0);
+    pub const MINOR_SECOND: Interval = Interval::from_parts(2, 1);
+    pub const MAJOR_SECOND: Interval = Interval::from_parts(2, 2);
+    pub const AUGMENTED_SECOND: Interval = Interval::from_parts(2, 3);
+    pub const MINOR_THIRD: Interval = Interval::from_parts(3, 3);
+    pub const MAJOR_THIRD: Interval = Interval::from_parts(3, 4);
+    pub const PERFECT_FOURTH: Interval = Interval::from_parts(4, 5);
+    pub const AUGMENTED_FOURTH: Interval = Interval::from_parts(4, 6);
+    pub const DIMINISHED_FIFTH: Interval = Interval::from_parts(5, 6);
+    pub const PERFECT_FIFTH: Interval = Interval::from_parts(5, 7);
+    pub const AUGMENTED_FIFTH: Interval = Interval::from_parts(5, 8);
+    pub const MINOR_SIXTH: Interval = Interval::from_parts(6, 8);
+    pub const MAJOR_SIXTH: Interval = Interval::from_parts(6, 9);
+    pub const DIMINISHED_SEVENTH: Interval = Interval::from_parts(7, 9);
+    pub const MINOR_SEVENTH: Interval = Interval::from_parts(7, 10);
+    pub const MAJOR_SEVENTH: Interval = Interval::from_parts(7, 11);
+    pub const PERFECT_OCTAVE: Interval = Interval::from_parts(8, 12);
+    pub const MINOR_NINTH: Interval = Interval::from_parts(9, 13);
+    pub const MAJOR_NINTH: Interval = Interval::from_parts(9, 14);
+    pub const AUGMENTED_NINTH: Interval = Interval::from_parts(9, 15);
+    pub const PERFECT_ELEVENTH: Interval = Interval::from_parts(11, 17);
+    pub const AUGMENTED_ELEVENTH: Interval = Interval::from_parts(11, 18);
+    pub const MINOR_THIRTEENTH: Interval = Interval::from_parts(13, 20);
+    pub const MAJOR_THIRTEENTH: Interval = Interval::from_parts(13, 21);
+    /// Largest interval that can be stored, a major 255th. Larger intervals saturate to it.
+    pub const MAX: Interval = Interval::from_parts(u8::MAX, Interval::reference_semitones(u8::MAX));
+
+    const fn from_parts(number: u8, semitones: NoteStep) -> Interval {
+        Interval { number, semitones }
+    }
+
+    /// Create an interval from its quality and number.
+    ///
+    /// Returns `None` when the quality can't be used with the number (a perfect third or a
+    /// major fifth) or when the interval would be smaller than a unison.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// assert_eq!(Interval::new(IntervalQuality::Minor, 3), Some(Interval::MINOR_THIRD));
+    /// assert_eq!(Interval::new(IntervalQuality::Augmented(1), 4).unwrap().semitones(), 6);
+    /// assert_eq!(Interval::new(IntervalQuality::Perfect, 3), None);
+    /// ```
+    pub fn new(quality: IntervalQuality, number: u8) -> Option<Interval> {
+        if number == 0 {
+            return None;
+        }
+        let reference = Self::reference_semitones(number);
+        let is_perfect = Self::is_perfect_number(number);
+        let semitones = match (quality, is_perfect) {
+            (IntervalQuality::Perfect, true) | (IntervalQuality::Major, false) => reference,
+            (IntervalQuality::Minor, false) => reference - 1,
+            (IntervalQuality::Augmented(times), _) if times > 0 => reference + times as NoteStep,
+            (IntervalQuality::Diminished(times), true) if times > 0 => {
+                reference - times as NoteStep
+            }
+            (IntervalQuality::Diminished(times), false) if times > 0 => {
+                reference - 1 - times as NoteStep
+            }
+            _ => return None,
+        };
+        if semitones < 0 {
+            return None;
+        }
+        Some(Interval { number, semitones })
+    }
+
+    /// Get the most common interval spanning the given number of semitones.
+    ///
+    /// The tritone is returned as an augmented fourth. When the number of semitones is
+    /// negative the interval of the absolute number of semitones is returned. Intervals larger
+    /// than [`Interval::MAX`] saturate to it.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// assert_eq!(Interval::from_semitones(4), Interval::MAJOR_THIRD);
+    /// assert_eq!(Interval::from_semitones(6), Interval::AUGMENTED_FOURTH);
+    /// assert_eq!(Interval::from_semitones(14), Interval::MAJOR_NINTH);
+    /// assert_eq!(Interval::from_semitones(-7), Interval::PERFECT_FIFTH);
+    /// assert_eq!(Interval::from_semitones(NoteStep::MIN), Interval::MAX);
+    /// ```
+    pub fn from_semitones(semitones: NoteStep) -> Interval {
+        let semitones = semitones.unsigned_abs();
+        if semitones > Self::MAX.semitones as u32 {
+            return Self::MAX;
+        }
+        let semitones = semitones as NoteStep;
+        let octaves = semitones / 12;
+        let number = NUMBER_BY_SEMITONES[(semitones % 12) as usize] as NoteStep + 7 * octaves;
+        Interval {
+            number: number as u8,
+            semitones,
+        }
+    }
+
+    /// Interval number, 1 is a unison, 3 is a third, 8 is an octave, 9 is a ninth.
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Number of semitones the interval spans.
+    pub fn semitones(&self) -> NoteStep {
+        self.semitones
+    }
+
+    pub fn quality(&self) -> IntervalQuality {
+        let difference = self.semitones - Self::reference_semitones(self.number);
+        match (Self::is_perfect_number(self.number), difference) {
+            (true, 0) => IntervalQuality::Perfect,
+            (false, 0) => IntervalQuality::Major,
+            (false, -1) => IntervalQuality::Minor,
+            (_, difference) if difference > 0 => IntervalQuality::Augmented(difference as u8),
+            (true, difference) => IntervalQuality::Diminished(-difference as u8),
+            (false, difference) => IntervalQuality::Diminished((-difference - 1) as u8),
+        }
+    }
+
+    /// Is this interval larger than an octave.
+    pub fn is_compound(&self) -> bool {
+        self.number > 8
+    }
+
+    /// Reduce a compound interval to an interval within a single octave.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// assert_eq!(Interval::MAJOR_NINTH.simple(), Interval::MAJOR_SECOND);
+    /// assert_eq!(Interval::PERFECT_OCTAVE.simple(), Interval::PERFECT_OCTAVE);
+    /// ```
+    pub fn simple(&self) -> Interval {
+        let octaves = (self.number as NoteStep - 2).max(0) / 7;
+        Interval {
+            number: (self.number as NoteStep - 7 * octaves) as u8,
+            semitones: self.semitones - 12 * octaves,
+        }
+    }
+
+    /// Inversion of the interval. Compound intervals are inverted as their simple interval.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// assert_eq!(Interval::MAJOR_THIRD.inversion(), Interval::MINOR_SIXTH);
+    /// assert_eq!(Interval::AUGMENTED_FOURTH.inversion(), Interval::DIMINISHED_FIFTH);
+    /// assert_eq!(Interval::PERFECT_UNISON.inversion(), Interval::PERFECT_OCTAVE);
+    /// ```
+    pub fn inversion(&self) -> Interval {
+        let simple = self.simple();
+        Interval {
+            number: 9 - simple.number,
+            semitones: 12 - simple.semitones,
+        }
+    }
+
+    fn is_perfect_number(number: u8) -> bool {
+        matches!((number - 1) % 7, 0 | 3 | 4)
+    }
+
+    const fn reference_semitones(number: u8) -> NoteStep {
+        let index = (number - 1) as usize;
+        REFERENCE_SEMITONES[index % 7] + 12 * (index / 7) as NoteStep
+    }
+}
+
+impl From<Interval> for NoteStep {
+    fn from(interval: Interval) -> Self {
+        interval.semitones
+    }
+}
+
+/// Add two intervals. Results larger than [`Interval::MAX`] saturate to it.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// assert_eq!(Interval::PERFECT_FIFTH + Interval::PERFECT_FOURTH, Interval::PERFECT_OCTAVE);
+/// assert_eq!(Interval::MAX + Interval::MAJOR_SECOND, Interval::MAX);
+/// ```
+impl Add for Interval {
+    type Output = Interval;
+    fn add(self, rhs: Interval) -> Self::Output {
+        let number = self.number as NoteStep + rhs.number as NoteStep - 1;
+        if number > u8::MAX as NoteStep {
+            return Interval::MAX;
+        }
+        Interval {
+            number: number as u8,
+            semitones: self.semitones + rhs.semitones,
+        }
+    }
+}
+
+/// Subtract a smaller interval.
+///
+/// # Panics
+///
+/// When `rhs` has a larger number than `self`.
+impl Sub for Interval {
+    type Output = Interval;
+    fn sub(self, rhs: Interval) -> Self::Output {
+        assert!(rhs.number <= self.number, "interval subtraction underflow");
+        Interval {
+            number: self.number - rhs.number + 1,
+            semitones: self.semitones - rhs.semitones,
+        }
+    }
+}
+
+impl Display for IntervalQuality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Diminished(times) => write!(f, "{}", "d".repeat(*times as usize)),
+            Self::Minor => write!(f, "m"),
+            Self::Perfect => write!(f, "P"),
+            Self::Major => write!(f, "M"),
+            Self::Augmented(times) => write!(f, "{}", "A".repeat(*times as usize)),
+        }
+    }
+}
+
+/// Short notation of the interval, for example `P5`, `m3` or `A4`.
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.quality(), self.number)
+    }
+}
+
+impl Add<Interval> for ChromaticNote {
+    type Output = ChromaticNote;
+    fn add(self, rhs: Interval) -> Self::Output {
+        self + rhs.semitones
+    }
+}
+
+impl Sub<Interval> for ChromaticNote {
+    type Output = ChromaticNote;
+    fn sub(self, rhs: Interval) -> Self::Output {
+        self + -rhs.semitones
+    }
+}
+
+/// Interval between two notes. The order of the notes doesn't matter. Notes that are further
+/// apart than [`Interval::MAX`] give [`Interval::MAX`].
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let c4 = ChromaticNote::new(ChromaticTone::C, 4);
+/// let e5 = ChromaticNote::new(ChromaticTone::E, 5);
+/// assert_eq!(c4 - e5, Interval::from_semitones(16));
+/// assert_eq!(ChromaticNote::max_value() - ChromaticNote::min_value(), Interval::MAX);
+/// ```
+impl Sub for ChromaticNote {
+    type Output = Interval;
+    fn sub(self, rhs: ChromaticNote) -> Self::Output {
+        Interval::from_semitones(i32::from(self) - i32::from(rhs))
+    }
+}
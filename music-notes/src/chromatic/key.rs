@@ -0,0 +1,161 @@
+use std::fmt::Display;
+
+use crate::{ChromaticNote, ChromaticScaleType, ChromaticTone, NoteRange, NoteStep, Octave};
+
+/// Scale starting at a specific root tone, for example D dorian or E flat major.
+///
+/// Degrees are zero based; the root of the key is degree 0.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let key = ChromaticKey::new(ChromaticTone::D, ChromaticScaleType::Major);
+/// assert!(key.contains(ChromaticNote::new(ChromaticTone::FSharp, 4)));
+/// assert!(!key.contains(ChromaticNote::new(ChromaticTone::F, 4)));
+/// assert_eq!(key.degree_of(ChromaticNote::new(ChromaticTone::A, 2)), Some(4));
+/// assert_eq!(key.note(4, 8), ChromaticNote::new(ChromaticTone::E, 5));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChromaticKey {
+    pub root: ChromaticTone,
+    pub scale_type: ChromaticScaleType,
+}
+
+impl ChromaticKey {
+    pub fn new(root: ChromaticTone, scale_type: ChromaticScaleType) -> Self {
+        Self { root, scale_type }
+    }
+
+    /// Does the key contain the tone of the given note.
+    pub fn contains(&self, note: ChromaticNote) -> bool {
+        self.contains_tone(note.tone)
+    }
+
+    /// Does the key contain the given tone.
+    pub fn contains_tone(&self, tone: ChromaticTone) -> bool {
+        self.scale_type.mask() & (1 << self.steps_above_root(tone)) != 0
+    }
+
+    /// Scale degree of the given note, `None` when the note isn't part of the key.
+    pub fn degree_of(&self, note: ChromaticNote) -> Option<usize> {
+        self.degree_of_tone(note.tone)
+    }
+
+    /// Scale degree of the given tone, `None` when the tone isn't part of the key.
+    pub fn degree_of_tone(&self, tone: ChromaticTone) -> Option<usize> {
+        let mask = self.scale_type.mask();
+        let steps = self.steps_above_root(tone);
+        if mask & (1 << steps) == 0 {
+            return None;
+        }
+        // The degree is the number of tones of the scale below the tone.
+        Some((mask & ((1 << steps) - 1)).count_ones() as usize)
+    }
+
+    /// Note of the given degree above the root in the given octave.
+    ///
+    /// Degrees can be negative or larger than the number of tones of the scale to get notes
+    /// in other octaves.
+    pub fn note(&self, octave: Octave, degree: NoteStep) -> ChromaticNote {
+        let note_steps = self.scale_type.note_steps();
+        let num_tones = note_steps.len() as NoteStep;
+        let octaves = degree.div_euclid(num_tones);
+        let step = note_steps[degree.rem_euclid(num_tones) as usize];
+        ChromaticNote::new(self.root, octave) + (octaves * 12 + step)
+    }
+
+    /// Note that is the given number of scale degrees above the given note. Negative degrees
+    /// go down.
+    ///
+    /// Returns `None` when the given note isn't part of the key.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let key = ChromaticKey::new(ChromaticTone::C, ChromaticScaleType::Major);
+    /// let b3 = ChromaticNote::new(ChromaticTone::B, 3);
+    /// assert_eq!(key.degrees_above(b3, 2), Some(ChromaticNote::new(ChromaticTone::D, 4)));
+    /// assert_eq!(key.degrees_above(b3, -3), Some(ChromaticNote::new(ChromaticTone::F, 3)));
+    ///
+    /// // Notes outside the range of a note are `None` as well.
+    /// let lowest = ChromaticNote::min_value();
+    /// assert_eq!(key.degrees_above(lowest, -1), None);
+    /// assert_eq!(key.degrees_above(ChromaticNote::max_value(), 1), None);
+    ///
+    /// // The root below the lowest octave isn't needed.
+    /// let key = ChromaticKey::new(ChromaticTone::D, ChromaticScaleType::Major);
+    /// let c_sharp = ChromaticNote::new(ChromaticTone::CSharp, Octave::MIN);
+    /// assert_eq!(
+    ///     key.degrees_above(c_sharp, 1),
+    ///     Some(ChromaticNote::new(ChromaticTone::D, Octave::MIN))
+    /// );
+    /// ```
+    pub fn degrees_above(&self, note: ChromaticNote, degrees: NoteStep) -> Option<ChromaticNote> {
+        let degree = self.degree_of(note)? as NoteStep;
+        // Work with note indices, the root below the note can be outside the octave range.
+        let root_index = i32::from(note) - self.steps_above_root(note.tone);
+        let note_steps = self.scale_type.note_steps();
+        let num_tones = note_steps.len() as NoteStep;
+        let degree = degree.checked_add(degrees)?;
+        let octaves = degree.div_euclid(num_tones);
+        let step = note_steps[degree.rem_euclid(num_tones) as usize];
+        let index = octaves.checked_mul(12)?.checked_add(root_index + step)?;
+        ChromaticNote::checked_from_index(index)
+    }
+
+    /// Iterate over the notes of the key between `first` and `last` (inclusive).
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let key = ChromaticKey::new(ChromaticTone::A, ChromaticScaleType::MinorPentatonic);
+    /// let notes: Vec<ChromaticNote> = key
+    ///     .notes(
+    ///         ChromaticNote::new(ChromaticTone::C, 4),
+    ///         ChromaticNote::new(ChromaticTone::C, 5),
+    ///     )
+    ///     .collect();
+    /// assert_eq!(
+    ///     notes,
+    ///     vec![
+    ///         ChromaticNote::new(ChromaticTone::C, 4),
+    ///         ChromaticNote::new(ChromaticTone::D, 4),
+    ///         ChromaticNote::new(ChromaticTone::E, 4),
+    ///         ChromaticNote::new(ChromaticTone::G, 4),
+    ///         ChromaticNote::new(ChromaticTone::A, 4),
+    ///         ChromaticNote::new(ChromaticTone::C, 5),
+    ///     ]
+    /// );
+    /// ```
+    pub fn notes(
+        &self,
+        first: ChromaticNote,
+        last: ChromaticNote,
+    ) -> impl Iterator<Item = ChromaticNote> + '_ {
+        NoteRange::new(first, last).notes_in_key(self)
+    }
+
+    /// Number of semitones the tone is above the root, within a single octave.
+    fn steps_above_root(&self, tone: ChromaticTone) -> NoteStep {
+        (u8::from(tone) as NoteStep - u8::from(self.root) as NoteStep).rem_euclid(12)
+    }
+}
+
+/// Name of the key, for example `Eb major` or `F# dorian`.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let key = ChromaticKey::new(ChromaticTone::DSharp, ChromaticScaleType::Major);
+/// assert_eq!(key.to_string(), "Eb major");
+/// ```
+impl Display for ChromaticKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.spelled_root(), self.scale_type)
+    }
+}
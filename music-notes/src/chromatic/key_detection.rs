@@ -0,0 +1,172 @@
+//! Key detection
+//!
+//! Finds the key of played notes using the Krumhansl-Schmuckler algorithm. The number of times
+//! each tone is played (the pitch class profile) is correlated with the key profiles of
+//! Krumhansl and Kessler for all major and minor keys. The key with the highest correlation is
+//! the most likely key. Only the most recent notes are used so the detected key follows
+//! modulations.
+use std::collections::VecDeque;
+
+use crate::{ChromaticKey, ChromaticNote, ChromaticScaleType, ChromaticTone};
+
+/// Krumhansl-Kessler profile of major keys, starting at the tonic.
+const MAJOR_PROFILE: [f64; 12] = [
+    6.35, 2.23, 3.48, 2.33, 4.38, 4.09, 2.52, 5.19, 2.39, 3.66, 2.29, 2.88,
+];
+
+/// Krumhansl-Kessler profile of minor keys, starting at the tonic.
+const MINOR_PROFILE: [f64; 12] = [
+    6.33, 2.68, 3.52, 5.38, 2.60, 3.53, 2.54, 4.75, 3.98, 2.69, 3.34, 3.17,
+];
+
+const DEFAULT_WINDOW: usize = 32;
+
+/// Key found by the key detector.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DetectedKey {
+    pub key: ChromaticKey,
+    /// Correlation between the played tones and the profile of the key, between -1 and 1.
+    pub correlation: f64,
+    /// Difference in correlation with the next most likely key. Values close to 0 mean that
+    /// another key is almost as likely.
+    pub confidence: f64,
+}
+
+/// Detect the key of a stream of notes.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let mut detector = KeyDetector::default();
+/// for tone in [
+///     ChromaticTone::G,
+///     ChromaticTone::A,
+///     ChromaticTone::B,
+///     ChromaticTone::C,
+///     ChromaticTone::D,
+///     ChromaticTone::E,
+///     ChromaticTone::FSharp,
+///     ChromaticTone::G,
+///     ChromaticTone::D,
+///     ChromaticTone::B,
+/// ] {
+///     detector.add_note(ChromaticNote::new(tone, 4));
+/// }
+/// let detected = detector.key().unwrap();
+/// assert_eq!(
+///     detected.key,
+///     ChromaticKey::new(ChromaticTone::G, ChromaticScaleType::Major)
+/// );
+/// assert!(detected.confidence > 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyDetector {
+    window: usize,
+    tones: VecDeque<(ChromaticTone, f64)>,
+}
+
+impl Default for KeyDetector {
+    fn default() -> Self {
+        KeyDetector::new(DEFAULT_WINDOW)
+    }
+}
+
+impl KeyDetector {
+    /// Create a key detector that uses the last `window` notes.
+    pub fn new(window: usize) -> KeyDetector {
+        KeyDetector {
+            window,
+            tones: VecDeque::with_capacity(window),
+        }
+    }
+
+    /// Add a played note.
+    pub fn add_note(&mut self, note: ChromaticNote) {
+        self.add_weighted(note.tone, 1.0);
+    }
+
+    /// Add a played tone with a weight, for example its duration.
+    pub fn add_weighted(&mut self, tone: ChromaticTone, weight: f64) {
+        if self.window == 0 {
+            return;
+        }
+        if self.tones.len() == self.window {
+            self.tones.pop_front();
+        }
+        self.tones.push_back((tone, weight));
+    }
+
+    /// Forget all added notes.
+    pub fn reset(&mut self) {
+        self.tones.clear();
+    }
+
+    /// Total weight of each tone in the window, starting at C.
+    pub fn profile(&self) -> [f64; 12] {
+        let mut profile = [0.0; 12];
+        for (tone, weight) in &self.tones {
+            profile[u8::from(*tone) as usize] += weight;
+        }
+        profile
+    }
+
+    /// All major and minor keys, the most likely key first.
+    ///
+    /// Returns an empty list when the played notes don't favour any tone, for example when no
+    /// notes were added yet.
+    pub fn candidates(&self) -> Vec<DetectedKey> {
+        let profile = self.profile();
+        let mut candidates: Vec<(ChromaticKey, f64)> = Vec::with_capacity(24);
+        for root in 0..12_u8 {
+            for (scale_type, key_profile) in [
+                (ChromaticScaleType::Major, &MAJOR_PROFILE),
+                (ChromaticScaleType::NaturalMinor, &MINOR_PROFILE),
+            ] {
+                let rotated: Vec<f64> = (0..12)
+                    .map(|tone| key_profile[(tone + 12 - root as usize) % 12])
+                    .collect();
+                let Some(correlation) = correlation(&profile, &rotated) else {
+                    return Vec::new();
+                };
+                let key = ChromaticKey::new(ChromaticTone::from(root), scale_type);
+                candidates.push((key, correlation));
+            }
+        }
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let correlations: Vec<f64> = candidates.iter().map(|(_, c)| *c).collect();
+        candidates
+            .into_iter()
+            .enumerate()
+            .map(|(index, (key, correlation))| DetectedKey {
+                key,
+                correlation,
+                confidence: correlation - correlations.get(index + 1).copied().unwrap_or(-1.0),
+            })
+            .collect()
+    }
+
+    /// Most likely key of the played notes.
+    pub fn key(&self) -> Option<DetectedKey> {
+        self.candidates().into_iter().next()
+    }
+}
+
+/// Pearson correlation of two profiles. `None` when one of the profiles is flat.
+fn correlation(a: &[f64], b: &[f64]) -> Option<f64> {
+    let mean_a = a.iter().sum::<f64>() / a.len() as f64;
+    let mean_b = b.iter().sum::<f64>() / b.len() as f64;
+    let mut covariance = 0.0;
+    let mut variance_a = 0.0;
+    let mut variance_b = 0.0;
+    for (a, b) in a.iter().zip(b) {
+        covariance += (a - mean_a) * (b - mean_b);
+        variance_a += (a - mean_a).powi(2);
+        variance_b += (b - mean_b).powi(2);
+    }
+    if variance_a == 0.0 || variance_b == 0.0 {
+        return None;
+    }
+    Some(covariance / (variance_a * variance_b).sqrt())
+}
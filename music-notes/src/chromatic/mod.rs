@@ -1,9 +1,39 @@
+mod chord_recognition;
+mod chord_symbol;
 mod chords;
+mod interval;
+mod key;
+mod key_detection;
+mod note_name;
+mod note_range;
 mod notes;
+mod pitch;
+mod pitch_class_set;
+mod quantizer;
+mod roman_numeral;
 mod scale;
+mod scale_type;
+mod spelling;
 mod tones;
+mod tuning;
+mod voicing;
 
+pub use chord_recognition::*;
+pub use chord_symbol::*;
 pub use chords::*;
+pub use interval::*;
+pub use key::*;
+pub use key_detection::*;
+pub use note_name::*;
+pub use note_range::*;
 pub use notes::*;
+pub use pitch::*;
+pub use pitch_class_set::*;
+pub use quantizer::*;
+pub use roman_numeral::*;
 pub use scale::*;
+pub use scale_type::*;
+pub use spelling::*;
 pub use tones::*;
+pub use tuning::*;
+pub use voicing::*;
@@ -0,0 +1,282 @@
+//! Note names
+//!
+//! Notes are written in scientific pitch notation: a letter, an optional accidental and the
+//! octave number, for example `C#4`, `Eb-1` or `A0`. Sequencers and keyboards don't agree on
+//! the octave numbering; Yamaha style software writes middle C (midi note 60) as `C3`.
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::{Accidental, ChromaticKey, ChromaticNote, Letter, NoteStep, Octave, SpelledTone};
+
+/// Numbering of the octaves in note names.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum OctaveConvention {
+    /// Middle C (midi note 60) is `C4`. Midi note 0 is `C-1`.
+    #[default]
+    Scientific,
+    /// Middle C (midi note 60) is `C3`. Midi note 0 is `C-2`.
+    Yamaha,
+}
+
+impl OctaveConvention {
+    /// Difference between the written octave and the octave of the note.
+    fn offset(&self) -> NoteStep {
+        match self {
+            Self::Scientific => 0,
+            Self::Yamaha => -1,
+        }
+    }
+}
+
+/// Accidental used for tones that aren't natural when no key is known.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AccidentalPreference {
+    #[default]
+    Sharp,
+    Flat,
+}
+
+/// Settings for writing and reading note names.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let note = ChromaticNote::new(ChromaticTone::DSharp, 4);
+/// assert_eq!(NoteNameFormat::default().format(note), "D#4");
+///
+/// let format = NoteNameFormat {
+///     octave_convention: OctaveConvention::Yamaha,
+///     accidental_preference: AccidentalPreference::Flat,
+///     unicode_accidentals: true,
+/// };
+/// assert_eq!(format.format(note), "E♭3");
+/// assert_eq!(format.parse("E♭3"), Ok(note));
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NoteNameFormat {
+    pub octave_convention: OctaveConvention,
+    pub accidental_preference: AccidentalPreference,
+    /// Write accidentals using the unicode symbols (♯, ♭, 𝄪, 𝄫) instead of `#` and `b`.
+    pub unicode_accidentals: bool,
+}
+
+impl NoteNameFormat {
+    /// Name of the note using the accidental preference.
+    pub fn format(&self, note: ChromaticNote) -> String {
+        let spelled = match self.accidental_preference {
+            AccidentalPreference::Sharp => SpelledTone::sharp(note.tone),
+            AccidentalPreference::Flat => SpelledTone::flat(note.tone),
+        };
+        self.format_spelled(note, spelled)
+    }
+
+    /// Name of the note spelled in the context of the given key.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let key = ChromaticKey::new(ChromaticTone::FSharp, ChromaticScaleType::Major);
+    /// let note = ChromaticNote::new(ChromaticTone::F, 4);
+    /// assert_eq!(NoteNameFormat::default().format_in_key(note, &key), "E#4");
+    /// ```
+    pub fn format_in_key(&self, note: ChromaticNote, key: &ChromaticKey) -> String {
+        self.format_spelled(note, key.spell(note.tone))
+    }
+
+    /// Name of the note using the given spelling.
+    ///
+    /// The octave number belongs to the letter, so C flat 4 sounds as B3 and B sharp 3 sounds
+    /// as C4.
+    ///
+    /// # Panics
+    ///
+    /// When the spelling doesn't match the tone of the note.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let format = NoteNameFormat::default();
+    /// let c_flat = SpelledTone::new(Letter::C, Accidental::Flat);
+    /// let b3 = ChromaticNote::new(ChromaticTone::B, 3);
+    /// assert_eq!(format.format_spelled(b3, c_flat), "Cb4");
+    /// assert_eq!(format.parse("Cb4"), Ok(b3));
+    /// ```
+    pub fn format_spelled(&self, note: ChromaticNote, spelled: SpelledTone) -> String {
+        assert_eq!(
+            note.tone,
+            spelled.into(),
+            "spelling {spelled} doesn't match the tone of the note"
+        );
+        let letter_index =
+            i32::from(note) - spelled.letter.semitones() - spelled.accidental.semitones();
+        let octave = letter_index.div_euclid(12) + self.octave_convention.offset();
+        let accidental = if self.unicode_accidentals {
+            unicode_accidental(spelled.accidental).to_string()
+        } else {
+            spelled.accidental.to_string()
+        };
+        format!("{}{accidental}{octave}", spelled.letter)
+    }
+
+    /// Read a note name.
+    ///
+    /// The letter is case insensitive. Accepted accidentals are `#`, `b`, `x`, `##`, `bb` and
+    /// their unicode symbols, including ♮. The octave is written using the octave convention
+    /// of the format.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let format = NoteNameFormat::default();
+    /// assert_eq!(format.parse("C#4"), Ok(ChromaticNote::new(ChromaticTone::CSharp, 4)));
+    /// assert_eq!(format.parse("eb-1"), Ok(ChromaticNote::new(ChromaticTone::DSharp, -1)));
+    /// assert_eq!(format.parse("Fx2"), Ok(ChromaticNote::new(ChromaticTone::G, 2)));
+    /// assert_eq!(format.parse("B#3"), Ok(ChromaticNote::new(ChromaticTone::C, 4)));
+    /// assert_eq!(format.parse("H4"), Err(ParseNoteError::InvalidLetter('H')));
+    /// assert_eq!(format.parse("C"), Err(ParseNoteError::MissingOctave));
+    /// ```
+    pub fn parse(&self, text: &str) -> Result<ChromaticNote, ParseNoteError> {
+        let (spelled, octave) = parse_spelled_tone(text.trim())?;
+        if octave.is_empty() {
+            return Err(ParseNoteError::MissingOctave);
+        }
+        let written_octave = octave
+            .parse::<NoteStep>()
+            .map_err(|_| ParseNoteError::InvalidOctave(octave.to_string()))?;
+
+        let octave_out_of_range = || ParseNoteError::OctaveOutOfRange(written_octave);
+        let letter_octave = written_octave
+            .checked_sub(self.octave_convention.offset())
+            .ok_or_else(octave_out_of_range)?;
+        let value = letter_octave
+            .checked_mul(12)
+            .and_then(|value| {
+                value.checked_add(spelled.letter.semitones() + spelled.accidental.semitones())
+            })
+            .ok_or_else(octave_out_of_range)?;
+        if Octave::try_from(value.div_euclid(12)).is_err() {
+            return Err(octave_out_of_range());
+        }
+        Ok(ChromaticNote::from(value))
+    }
+}
+
+/// Read the letter and accidental at the start of the text. Returns the spelled tone and the
+/// remaining text.
+pub(crate) fn parse_spelled_tone(text: &str) -> Result<(SpelledTone, &str), ParseNoteError> {
+    let mut chars = text.chars();
+    let letter = chars.next().ok_or(ParseNoteError::Empty)?;
+    let letter = parse_letter(letter).ok_or(ParseNoteError::InvalidLetter(letter))?;
+
+    let rest = chars.as_str();
+    let (accidental, rest) = ACCIDENTALS
+        .iter()
+        .find(|(symbol, _)| rest.starts_with(symbol))
+        .map(|(symbol, accidental)| (*accidental, &rest[symbol.len()..]))
+        .unwrap_or((Accidental::Natural, rest));
+    Ok((SpelledTone::new(letter, accidental), rest))
+}
+
+/// Accidentals that can be read, longest symbols first.
+const ACCIDENTALS: [(&str, Accidental); 12] = [
+    ("##", Accidental::DoubleSharp),
+    ("bb", Accidental::DoubleFlat),
+    ("♯♯", Accidental::DoubleSharp),
+    ("♭♭", Accidental::DoubleFlat),
+    ("x", Accidental::DoubleSharp),
+    ("𝄪", Accidental::DoubleSharp),
+    ("𝄫", Accidental::DoubleFlat),
+    ("#", Accidental::Sharp),
+    ("♯", Accidental::Sharp),
+    ("b", Accidental::Flat),
+    ("♭", Accidental::Flat),
+    ("♮", Accidental::Natural),
+];
+
+fn parse_letter(letter: char) -> Option<Letter> {
+    match letter.to_ascii_uppercase() {
+        'C' => Some(Letter::C),
+        'D' => Some(Letter::D),
+        'E' => Some(Letter::E),
+        'F' => Some(Letter::F),
+        'G' => Some(Letter::G),
+        'A' => Some(Letter::A),
+        'B' => Some(Letter::B),
+        _ => None,
+    }
+}
+
+fn unicode_accidental(accidental: Accidental) -> &'static str {
+    match accidental {
+        Accidental::DoubleFlat => "𝄫",
+        Accidental::Flat => "♭",
+        Accidental::Natural => "",
+        Accidental::Sharp => "♯",
+        Accidental::DoubleSharp => "𝄪",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseNoteError {
+    /// The text doesn't contain a note name.
+    Empty,
+    /// The note name doesn't start with one of the letters A to G.
+    InvalidLetter(char),
+    /// The note name doesn't end with an octave number.
+    MissingOctave,
+    /// The text after the letter and accidental isn't an octave number.
+    InvalidOctave(String),
+    /// The octave number can't be stored in a note.
+    OctaveOutOfRange(NoteStep),
+}
+
+impl Display for ParseNoteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "note name is empty"),
+            Self::InvalidLetter(letter) => {
+                write!(f, "`{letter}` isn't a note letter, expected A to G")
+            }
+            Self::MissingOctave => write!(f, "note name doesn't have an octave"),
+            Self::InvalidOctave(octave) => write!(f, "`{octave}` isn't a valid octave"),
+            Self::OctaveOutOfRange(octave) => write!(f, "octave {octave} is out of range"),
+        }
+    }
+}
+
+impl Error for ParseNoteError {}
+
+/// Scientific pitch notation using sharps, for example `C#4`.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// assert_eq!(ChromaticNote::new(ChromaticTone::A, 0).to_string(), "A0");
+/// assert_eq!(ChromaticNote::new(ChromaticTone::DSharp, -1).to_string(), "D#-1");
+/// ```
+impl Display for ChromaticNote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", NoteNameFormat::default().format(*self))
+    }
+}
+
+/// Read a note name in scientific pitch notation.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let note: ChromaticNote = "Eb-1".parse().unwrap();
+/// assert_eq!(note, ChromaticNote::new(ChromaticTone::DSharp, -1));
+/// assert!("C#".parse::<ChromaticNote>().is_err());
+/// ```
+impl FromStr for ChromaticNote {
+    type Err = ParseNoteError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NoteNameFormat::default().parse(s)
+    }
+}
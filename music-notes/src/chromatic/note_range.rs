@@ -0,0 +1,258 @@
+use std::fmt::Display;
+
+use crate::{ChromaticKey, ChromaticNote, ChromaticTone, NoteStep};
+
+/// Range of notes between a lowest and a highest note, both included.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let range = NoteRange::new(
+///     ChromaticNote::new(ChromaticTone::A, 3),
+///     ChromaticNote::new(ChromaticTone::C, 4),
+/// );
+/// assert_eq!(range.len(), 4);
+/// assert!(range.contains(ChromaticNote::new(ChromaticTone::B, 3)));
+/// assert_eq!(
+///     range.iter().rev().collect::<Vec<ChromaticNote>>(),
+///     vec![
+///         ChromaticNote::new(ChromaticTone::C, 4),
+///         ChromaticNote::new(ChromaticTone::B, 3),
+///         ChromaticNote::new(ChromaticTone::ASharp, 3),
+///         ChromaticNote::new(ChromaticTone::A, 3),
+///     ]
+/// );
+/// assert_eq!(NoteRange::PIANO.iter().count(), 88);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NoteRange {
+    lowest: ChromaticNote,
+    highest: ChromaticNote,
+}
+
+impl NoteRange {
+    /// All notes that can be sent over midi, C-1 to G9.
+    pub const MIDI: NoteRange = NoteRange::from_notes(
+        ChromaticNote {
+            tone: ChromaticTone::C,
+            octave: -1,
+        },
+        ChromaticNote {
+            tone: ChromaticTone::G,
+            octave: 9,
+        },
+    );
+
+    /// Keys of an 88-key piano, A0 to C8.
+    pub const PIANO: NoteRange = NoteRange::from_notes(
+        ChromaticNote {
+            tone: ChromaticTone::A,
+            octave: 0,
+        },
+        ChromaticNote {
+            tone: ChromaticTone::C,
+            octave: 8,
+        },
+    );
+
+    /// Guitar in standard tuning with 24 frets, E2 to E6.
+    pub const GUITAR: NoteRange = NoteRange::from_notes(
+        ChromaticNote {
+            tone: ChromaticTone::E,
+            octave: 2,
+        },
+        ChromaticNote {
+            tone: ChromaticTone::E,
+            octave: 6,
+        },
+    );
+
+    /// Keys of the General MIDI percussion map, from the acoustic bass drum (midi note 35, B1)
+    /// to the open triangle (midi note 81, A5).
+    pub const GENERAL_MIDI_DRUMS: NoteRange = NoteRange::from_notes(
+        ChromaticNote {
+            tone: ChromaticTone::B,
+            octave: 1,
+        },
+        ChromaticNote {
+            tone: ChromaticTone::A,
+            octave: 5,
+        },
+    );
+
+    /// Create a range between two notes. The order of the notes doesn't matter.
+    pub fn new(first: ChromaticNote, last: ChromaticNote) -> NoteRange {
+        if i32::from(first) <= i32::from(last) {
+            NoteRange::from_notes(first, last)
+        } else {
+            NoteRange::from_notes(last, first)
+        }
+    }
+
+    const fn from_notes(lowest: ChromaticNote, highest: ChromaticNote) -> NoteRange {
+        NoteRange { lowest, highest }
+    }
+
+    pub fn lowest(&self) -> ChromaticNote {
+        self.lowest
+    }
+
+    pub fn highest(&self) -> ChromaticNote {
+        self.highest
+    }
+
+    /// Number of notes in the range.
+    pub fn len(&self) -> usize {
+        (i32::from(self.highest) - i32::from(self.lowest)) as usize + 1
+    }
+
+    /// A range always contains at least one note.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn contains(&self, note: ChromaticNote) -> bool {
+        (i32::from(self.lowest)..=i32::from(self.highest)).contains(&i32::from(note))
+    }
+
+    /// Nearest note inside the range.
+    pub fn clamp(&self, note: ChromaticNote) -> ChromaticNote {
+        ChromaticNote::from(i32::from(note).clamp(i32::from(self.lowest), i32::from(self.highest)))
+    }
+
+    /// Move the note by whole octaves until it is inside the range, keeping its tone. Notes
+    /// below the range end up in its lowest octave, notes above it in its highest octave.
+    ///
+    /// Returns `None` when the range is smaller than an octave and doesn't contain the tone.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let range = NoteRange::GUITAR;
+    /// assert_eq!(
+    ///     range.fold(ChromaticNote::new(ChromaticTone::C, 1)),
+    ///     Some(ChromaticNote::new(ChromaticTone::C, 3))
+    /// );
+    /// assert_eq!(
+    ///     range.fold(ChromaticNote::new(ChromaticTone::G, 7)),
+    ///     Some(ChromaticNote::new(ChromaticTone::G, 5))
+    /// );
+    /// ```
+    pub fn fold(&self, note: ChromaticNote) -> Option<ChromaticNote> {
+        let index = i32::from(note);
+        let lowest = i32::from(self.lowest);
+        let highest = i32::from(self.highest);
+        let folded = if index < lowest {
+            ChromaticNote::from(lowest + (index - lowest).rem_euclid(12))
+        } else if index > highest {
+            ChromaticNote::from(highest - (highest - index).rem_euclid(12))
+        } else {
+            note
+        };
+        self.contains(folded).then_some(folded)
+    }
+
+    /// Notes that are in both ranges, `None` when the ranges don't overlap.
+    pub fn intersection(&self, other: &NoteRange) -> Option<NoteRange> {
+        let lowest = i32::from(self.lowest).max(i32::from(other.lowest));
+        let highest = i32::from(self.highest).min(i32::from(other.highest));
+        (lowest <= highest).then(|| {
+            NoteRange::from_notes(ChromaticNote::from(lowest), ChromaticNote::from(highest))
+        })
+    }
+
+    /// Range moved up or down by the given number of steps.
+    pub fn transpose(&self, steps: NoteStep) -> Option<NoteRange> {
+        Some(NoteRange::from_notes(
+            self.lowest.checked_add(steps)?,
+            self.highest.checked_add(steps)?,
+        ))
+    }
+
+    /// Notes of the range from low to high.
+    pub fn iter(&self) -> NoteRangeIter {
+        NoteRangeIter {
+            next: i32::from(self.lowest),
+            last: i32::from(self.highest),
+        }
+    }
+
+    /// Notes of the range that are part of the given key.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let key = ChromaticKey::new(ChromaticTone::C, ChromaticScaleType::Major);
+    /// assert_eq!(NoteRange::PIANO.notes_in_key(&key).count(), 52);
+    /// ```
+    pub fn notes_in_key<'a>(
+        &self,
+        key: &'a ChromaticKey,
+    ) -> impl Iterator<Item = ChromaticNote> + 'a {
+        self.iter().filter(|note| key.contains(*note))
+    }
+}
+
+impl IntoIterator for NoteRange {
+    type Item = ChromaticNote;
+    type IntoIter = NoteRangeIter;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &NoteRange {
+    type Item = ChromaticNote;
+    type IntoIter = NoteRangeIter;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the notes of a [`NoteRange`].
+#[derive(Debug, Clone)]
+pub struct NoteRangeIter {
+    next: i32,
+    last: i32,
+}
+
+impl Iterator for NoteRangeIter {
+    type Item = ChromaticNote;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next > self.last {
+            return None;
+        }
+        let note = ChromaticNote::from(self.next);
+        self.next += 1;
+        Some(note)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.last - self.next + 1).max(0) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for NoteRangeIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next > self.last {
+            return None;
+        }
+        let note = ChromaticNote::from(self.last);
+        self.last -= 1;
+        Some(note)
+    }
+}
+
+impl ExactSizeIterator for NoteRangeIter {}
+
+/// Range written as the lowest and highest note, for example `A0-C8`.
+impl Display for NoteRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.lowest, self.highest)
+    }
+}
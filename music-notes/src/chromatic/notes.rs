@@ -1,9 +1,132 @@
+use std::{error::Error, fmt::Display};
+
 use crate::{ChromaticTone, Note};
 
 pub type ChromaticNote = Note<ChromaticTone>;
 
+/// Number of the highest midi note (G9).
+pub const MIDI_NOTE_MAX: u8 = 127;
+
+/// Midi note 0 is C-1.
+const MIDI_NOTE_OFFSET: i32 = 12;
+
 impl Default for ChromaticNote {
     fn default() -> Self {
         ChromaticNote::new(ChromaticTone::C, 4)
     }
 }
+
+/// Conversion between chromatic notes and midi note numbers. Midi note 60 is C4 (middle C).
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// assert_eq!(ChromaticNote::from_midi(60), Ok(ChromaticNote::new(ChromaticTone::C, 4)));
+/// assert_eq!(ChromaticNote::new(ChromaticTone::A, 4).to_midi(), Ok(69));
+/// assert_eq!(ChromaticNote::from_midi(128), Err(MidiNoteError::InvalidMidiNote(128)));
+///
+/// // Notes outside the midi range are rejected or clamped.
+/// let c10 = ChromaticNote::new(ChromaticTone::C, 10);
+/// assert_eq!(c10.to_midi(), Err(MidiNoteError::NoteOutOfRange(c10)));
+/// assert_eq!(c10.to_midi_saturating(), 127);
+/// ```
+impl ChromaticNote {
+    /// Note of the given midi note number.
+    pub fn from_midi(midi_note: u8) -> Result<ChromaticNote, MidiNoteError> {
+        if midi_note > MIDI_NOTE_MAX {
+            return Err(MidiNoteError::InvalidMidiNote(midi_note));
+        }
+        Ok(ChromaticNote::from(midi_note as i32 - MIDI_NOTE_OFFSET))
+    }
+
+    /// Midi note number of the note.
+    pub fn to_midi(&self) -> Result<u8, MidiNoteError> {
+        let midi_note = i32::from(*self) + MIDI_NOTE_OFFSET;
+        if (0..=MIDI_NOTE_MAX as i32).contains(&midi_note) {
+            Ok(midi_note as u8)
+        } else {
+            Err(MidiNoteError::NoteOutOfRange(*self))
+        }
+    }
+
+    /// Midi note number of the note. Notes outside the midi range are clamped to the lowest
+    /// or highest midi note.
+    pub fn to_midi_saturating(&self) -> u8 {
+        (i32::from(*self) + MIDI_NOTE_OFFSET).clamp(0, MIDI_NOTE_MAX as i32) as u8
+    }
+}
+
+impl TryFrom<u8> for ChromaticNote {
+    type Error = MidiNoteError;
+    fn try_from(midi_note: u8) -> Result<Self, Self::Error> {
+        ChromaticNote::from_midi(midi_note)
+    }
+}
+
+impl TryFrom<ChromaticNote> for u8 {
+    type Error = MidiNoteError;
+    fn try_from(note: ChromaticNote) -> Result<Self, Self::Error> {
+        note.to_midi()
+    }
+}
+
+/// Error when converting between notes and midi note numbers.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MidiNoteError {
+    /// The midi note number is higher than [`MIDI_NOTE_MAX`].
+    InvalidMidiNote(u8),
+    /// The note is lower than C-1 or higher than G9.
+    NoteOutOfRange(ChromaticNote),
+}
+
+impl Display for MidiNoteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidMidiNote(midi_note) => {
+                write!(
+                    f,
+                    "{midi_note} isn't a midi note, expected 0 to {MIDI_NOTE_MAX}"
+                )
+            }
+            Self::NoteOutOfRange(note) => write!(f, "{note} is outside the midi note range"),
+        }
+    }
+}
+
+impl Error for MidiNoteError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChromaticNote, MidiNoteError, MIDI_NOTE_MAX};
+
+    /// All midi notes round trip, other bytes are rejected.
+    #[test]
+    fn midi_bytes() {
+        for byte in 0..=u8::MAX {
+            match ChromaticNote::try_from(byte) {
+                Ok(note) => assert_eq!(u8::try_from(note), Ok(byte)),
+                Err(error) => {
+                    assert!(byte > MIDI_NOTE_MAX);
+                    assert_eq!(error, MidiNoteError::InvalidMidiNote(byte));
+                }
+            }
+        }
+    }
+
+    /// Notes outside the midi range are rejected, over the whole range of notes.
+    #[test]
+    fn notes_outside_midi_range() {
+        let min = i32::from(ChromaticNote::min_value());
+        let max = i32::from(ChromaticNote::max_value());
+        for index in min..=max {
+            let note = ChromaticNote::from(index);
+            let in_range = (-12..=115).contains(&index);
+            assert_eq!(note.to_midi().is_ok(), in_range);
+            if !in_range {
+                assert_eq!(note.to_midi(), Err(MidiNoteError::NoteOutOfRange(note)));
+            }
+            assert_eq!(note.to_midi_saturating() as i32, (index + 12).clamp(0, 127));
+        }
+    }
+}
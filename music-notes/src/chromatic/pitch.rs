@@ -0,0 +1,33 @@
+use crate::{ChromaticNote, ChromaticTone, ReferencePitch};
+
+/// Frequency of A4 in standard concert pitch (ISO 16).
+pub const CONCERT_PITCH_A4: f64 = 440.0;
+/// Frequency of A4 used by some musicians as an alternative to concert pitch.
+pub const ALTERNATIVE_PITCH_A4: f64 = 432.0;
+/// Frequency of A4 commonly used when performing baroque music.
+pub const BAROQUE_PITCH_A4: f64 = 415.0;
+
+pub type ChromaticReferencePitch = ReferencePitch<ChromaticTone>;
+
+impl ChromaticReferencePitch {
+    /// Create a reference pitch where A4 has the given frequency.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let reference = ChromaticReferencePitch::a4(BAROQUE_PITCH_A4);
+    /// let pitch = reference.pitch(ChromaticNote::new(ChromaticTone::A, 3));
+    /// assert!((pitch.frequency - 207.5).abs() < 1e-9);
+    /// ```
+    pub fn a4(frequency: f64) -> Self {
+        Self::new(ChromaticNote::new(ChromaticTone::A, 4), frequency)
+    }
+}
+
+/// Default reference pitch is concert pitch (A4 = 440Hz).
+impl Default for ChromaticReferencePitch {
+    fn default() -> Self {
+        Self::a4(CONCERT_PITCH_A4)
+    }
+}
@@ -0,0 +1,346 @@
+//! Pitch class sets
+//!
+//! A pitch class set is a set of chromatic tones without octave, stored as a bit mask. Sets
+//! are compared and classified using the operations of musical set theory: transposition,
+//! inversion, normal form, prime form, interval vectors and Forte numbers.
+use std::fmt::Display;
+
+use crate::{ChromaticNote, ChromaticTone, NoteStep};
+
+const ALL_PITCH_CLASSES: u16 = 0xfff;
+
+/// Forte names and prime forms of the set classes with 3 to 6 pitch classes. Set classes with
+/// 7 to 9 pitch classes are named after their complement.
+#[rustfmt::skip]
+const FORTE_SET_CLASSES: [(&str, &[u8]); 129] = [
+    ("3-1", &[0, 1, 2]), ("3-2", &[0, 1, 3]), ("3-3", &[0, 1, 4]), ("3-4", &[0, 1, 5]),
+    ("3-5", &[0, 1, 6]), ("3-6", &[0, 2, 4]), ("3-7", &[0, 2, 5]), ("3-8", &[0, 2, 6]),
+    ("3-9", &[0, 2, 7]), ("3-10", &[0, 3, 6]), ("3-11", &[0, 3, 7]), ("3-12", &[0, 4, 8]),
+
+    ("4-1", &[0, 1, 2, 3]), ("4-2", &[0, 1, 2, 4]), ("4-3", &[0, 1, 3, 4]),
+    ("4-4", &[0, 1, 2, 5]), ("4-5", &[0, 1, 2, 6]), ("4-6", &[0, 1, 2, 7]),
+    ("4-7", &[0, 1, 4, 5]), ("4-8", &[0, 1, 5, 6]), ("4-9", &[0, 1, 6, 7]),
+    ("4-10", &[0, 2, 3, 5]), ("4-11", &[0, 1, 3, 5]), ("4-12", &[0, 2, 3, 6]),
+    ("4-13", &[0, 1, 3, 6]), ("4-14", &[0, 2, 3, 7]), ("4-Z15", &[0, 1, 4, 6]),
+    ("4-16", &[0, 1, 5, 7]), ("4-17", &[0, 3, 4, 7]), ("4-18", &[0, 1, 4, 7]),
+    ("4-19", &[0, 1, 4, 8]), ("4-20", &[0, 1, 5, 8]), ("4-21", &[0, 2, 4, 6]),
+    ("4-22", &[0, 2, 4, 7]), ("4-23", &[0, 2, 5, 7]), ("4-24", &[0, 2, 4, 8]),
+    ("4-25", &[0, 2, 6, 8]), ("4-26", &[0, 3, 5, 8]), ("4-27", &[0, 2, 5, 8]),
+    ("4-28", &[0, 3, 6, 9]), ("4-Z29", &[0, 1, 3, 7]),
+
+    ("5-1", &[0, 1, 2, 3, 4]), ("5-2", &[0, 1, 2, 3, 5]), ("5-3", &[0, 1, 2, 4, 5]),
+    ("5-4", &[0, 1, 2, 3, 6]), ("5-5", &[0, 1, 2, 3, 7]), ("5-6", &[0, 1, 2, 5, 6]),
+    ("5-7", &[0, 1, 2, 6, 7]), ("5-8", &[0, 2, 3, 4, 6]), ("5-9", &[0, 1, 2, 4, 6]),
+    ("5-10", &[0, 1, 3, 4, 6]), ("5-11", &[0, 2, 3, 4, 7]), ("5-Z12", &[0, 1, 3, 5, 6]),
+    ("5-13", &[0, 1, 2, 4, 8]), ("5-14", &[0, 1, 2, 5, 7]), ("5-15", &[0, 1, 2, 6, 8]),
+    ("5-16", &[0, 1, 3, 4, 7]), ("5-Z17", &[0, 1, 3, 4, 8]), ("5-Z18", &[0, 1, 4, 5, 7]),
+    ("5-19", &[0, 1, 3, 6, 7]), ("5-20", &[0, 1, 5, 6, 8]), ("5-21", &[0, 1, 4, 5, 8]),
+    ("5-22", &[0, 1, 4, 7, 8]), ("5-23", &[0, 2, 3, 5, 7]), ("5-24", &[0, 1, 3, 5, 7]),
+    ("5-25", &[0, 2, 3, 5, 8]), ("5-26", &[0, 2, 4, 5, 8]), ("5-27", &[0, 1, 3, 5, 8]),
+    ("5-28", &[0, 2, 3, 6, 8]), ("5-29", &[0, 1, 3, 6, 8]), ("5-30", &[0, 1, 4, 6, 8]),
+    ("5-31", &[0, 1, 3, 6, 9]), ("5-32", &[0, 1, 4, 6, 9]), ("5-33", &[0, 2, 4, 6, 8]),
+    ("5-34", &[0, 2, 4, 6, 9]), ("5-35", &[0, 2, 4, 7, 9]), ("5-Z36", &[0, 1, 2, 4, 7]),
+    ("5-Z37", &[0, 3, 4, 5, 8]), ("5-Z38", &[0, 1, 2, 5, 8]),
+
+    ("6-1", &[0, 1, 2, 3, 4, 5]), ("6-2", &[0, 1, 2, 3, 4, 6]),
+    ("6-Z3", &[0, 1, 2, 3, 5, 6]), ("6-Z4", &[0, 1, 2, 4, 5, 6]),
+    ("6-5", &[0, 1, 2, 3, 6, 7]), ("6-Z6", &[0, 1, 2, 5, 6, 7]),
+    ("6-7", &[0, 1, 2, 6, 7, 8]), ("6-8", &[0, 2, 3, 4, 5, 7]),
+    ("6-9", &[0, 1, 2, 3, 5, 7]), ("6-Z10", &[0, 1, 3, 4, 5, 7]),
+    ("6-Z11", &[0, 1, 2, 4, 5, 7]), ("6-Z12", &[0, 1, 2, 4, 6, 7]),
+    ("6-Z13", &[0, 1, 3, 4, 6, 7]), ("6-14", &[0, 1, 3, 4, 5, 8]),
+    ("6-15", &[0, 1, 2, 4, 5, 8]), ("6-16", &[0, 1, 4, 5, 6, 8]),
+    ("6-Z17", &[0, 1, 2, 4, 7, 8]), ("6-18", &[0, 1, 2, 5, 7, 8]),
+    ("6-Z19", &[0, 1, 3, 4, 7, 8]), ("6-20", &[0, 1, 4, 5, 8, 9]),
+    ("6-21", &[0, 2, 3, 4, 6, 8]), ("6-22", &[0, 1, 2, 4, 6, 8]),
+    ("6-Z23", &[0, 2, 3, 5, 6, 8]), ("6-Z24", &[0, 1, 3, 4, 6, 8]),
+    ("6-Z25", &[0, 1, 3, 5, 6, 8]), ("6-Z26", &[0, 1, 3, 5, 7, 8]),
+    ("6-27", &[0, 1, 3, 4, 6, 9]), ("6-Z28", &[0, 1, 3, 5, 6, 9]),
+    ("6-Z29", &[0, 1, 3, 6, 8, 9]), ("6-30", &[0, 1, 3, 6, 7, 9]),
+    ("6-31", &[0, 1, 3, 5, 8, 9]), ("6-32", &[0, 2, 4, 5, 7, 9]),
+    ("6-33", &[0, 2, 3, 5, 7, 9]), ("6-34", &[0, 1, 3, 5, 7, 9]),
+    ("6-35", &[0, 2, 4, 6, 8, 10]), ("6-Z36", &[0, 1, 2, 3, 4, 7]),
+    ("6-Z37", &[0, 1, 2, 3, 4, 8]), ("6-Z38", &[0, 1, 2, 3, 7, 8]),
+    ("6-Z39", &[0, 2, 3, 4, 5, 8]), ("6-Z40", &[0, 1, 2, 3, 5, 8]),
+    ("6-Z41", &[0, 1, 2, 3, 6, 8]), ("6-Z42", &[0, 1, 2, 3, 6, 9]),
+    ("6-Z43", &[0, 1, 2, 5, 6, 8]), ("6-Z44", &[0, 1, 2, 5, 6, 9]),
+    ("6-Z45", &[0, 2, 3, 4, 6, 9]), ("6-Z46", &[0, 1, 2, 4, 6, 9]),
+    ("6-Z47", &[0, 1, 2, 4, 7, 9]), ("6-Z48", &[0, 1, 2, 5, 7, 9]),
+    ("6-Z49", &[0, 1, 3, 4, 7, 9]), ("6-Z50", &[0, 1, 4, 6, 7, 9]),
+];
+
+/// Set of chromatic tones without octave.
+///
+/// Pitch class 0 is C, 11 is B.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let c_major = PitchClassSet::from_pitch_classes(&[0, 4, 7]);
+/// let a_minor = PitchClassSet::from_pitch_classes(&[9, 0, 4]);
+/// assert_eq!(c_major.transpose(9).invert().prime_form(), a_minor.prime_form());
+/// assert_eq!(c_major.forte_number(), Some("3-11"));
+/// assert_eq!(c_major.interval_vector(), [0, 0, 1, 1, 1, 0]);
+/// assert_eq!(c_major.to_string(), "[0,4,7]");
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PitchClassSet {
+    mask: u16,
+}
+
+impl PitchClassSet {
+    pub const EMPTY: PitchClassSet = PitchClassSet { mask: 0 };
+    pub const CHROMATIC: PitchClassSet = PitchClassSet {
+        mask: ALL_PITCH_CLASSES,
+    };
+
+    /// Create a set from a bit mask. Bit `n` is set when pitch class `n` is part of the set.
+    /// Bits above bit 11 are ignored.
+    pub fn new(mask: u16) -> Self {
+        Self {
+            mask: mask & ALL_PITCH_CLASSES,
+        }
+    }
+
+    /// Create a set from pitch class numbers. Numbers are reduced to a single octave.
+    pub fn from_pitch_classes(pitch_classes: &[u8]) -> Self {
+        Self::new(
+            pitch_classes
+                .iter()
+                .fold(0, |mask, pitch_class| mask | (1 << (pitch_class % 12))),
+        )
+    }
+
+    /// Create a set from the tones of the given notes.
+    pub fn from_notes(notes: &[ChromaticNote]) -> Self {
+        notes.iter().map(|note| note.tone).collect()
+    }
+
+    /// Bit mask of the set. Bit `n` is set when pitch class `n` is part of the set.
+    pub fn mask(&self) -> u16 {
+        self.mask
+    }
+
+    /// Number of pitch classes in the set.
+    pub fn len(&self) -> usize {
+        self.mask.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mask == 0
+    }
+
+    pub fn contains(&self, tone: ChromaticTone) -> bool {
+        self.mask & (1 << u8::from(tone)) != 0
+    }
+
+    pub fn insert(&mut self, tone: ChromaticTone) {
+        self.mask |= 1 << u8::from(tone);
+    }
+
+    pub fn remove(&mut self, tone: ChromaticTone) {
+        self.mask &= !(1 << u8::from(tone));
+    }
+
+    /// Pitch classes of the set in ascending order.
+    pub fn pitch_classes(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..12).filter(|pitch_class| self.mask & (1 << pitch_class) != 0)
+    }
+
+    /// Tones of the set in ascending order.
+    pub fn tones(&self) -> impl Iterator<Item = ChromaticTone> + '_ {
+        self.pitch_classes().map(ChromaticTone::from)
+    }
+
+    pub fn union(&self, other: PitchClassSet) -> PitchClassSet {
+        Self::new(self.mask | other.mask)
+    }
+
+    pub fn intersection(&self, other: PitchClassSet) -> PitchClassSet {
+        Self::new(self.mask & other.mask)
+    }
+
+    /// Pitch classes that aren't part of the set.
+    pub fn complement(&self) -> PitchClassSet {
+        Self::new(!self.mask)
+    }
+
+    /// Are all pitch classes of this set part of the other set.
+    pub fn is_subset_of(&self, other: PitchClassSet) -> bool {
+        self.mask & !other.mask == 0
+    }
+
+    /// Transpose the set by the given number of semitones (Tn).
+    pub fn transpose(&self, steps: NoteStep) -> PitchClassSet {
+        let steps = steps.rem_euclid(12) as u32;
+        let mask = self.mask as u32;
+        Self::new(((mask << steps) | (mask >> (12 - steps))) as u16)
+    }
+
+    /// Invert the set around pitch class 0 (I).
+    pub fn invert(&self) -> PitchClassSet {
+        self.pitch_classes()
+            .map(|pitch_class| ChromaticTone::from((12 - pitch_class) % 12))
+            .collect()
+    }
+
+    /// Normal form: the ordering of the pitch classes that spans the smallest interval.
+    ///
+    /// Ties are broken by the smallest interval between the first and the next to last pitch
+    /// class, and so on towards the second pitch class. When the set is symmetric the
+    /// ordering starting with the lowest pitch class is used.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let set = PitchClassSet::from_pitch_classes(&[11, 2, 7]);
+    /// assert_eq!(set.normal_form(), vec![7, 11, 2]);
+    /// ```
+    pub fn normal_form(&self) -> Vec<u8> {
+        let pitch_classes: Vec<u8> = self.pitch_classes().collect();
+        let len = pitch_classes.len();
+        (0..len)
+            .map(|start| {
+                (0..len)
+                    .map(|index| pitch_classes[(start + index) % len])
+                    .collect::<Vec<u8>>()
+            })
+            .min_by_key(|rotation| {
+                let spans: Vec<u8> = rotation
+                    .iter()
+                    .rev()
+                    .map(|pitch_class| (pitch_class + 12 - rotation[0]) % 12)
+                    .collect();
+                (spans, rotation[0])
+            })
+            .unwrap_or_default()
+    }
+
+    /// Prime form: the most compact form of the set or its inversion, transposed to start at
+    /// pitch class 0. Sets with the same prime form belong to the same set class.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let dominant_seventh = PitchClassSet::from_pitch_classes(&[7, 11, 2, 5]);
+    /// assert_eq!(dominant_seventh.prime_form().to_string(), "[0,2,5,8]");
+    /// ```
+    pub fn prime_form(&self) -> PitchClassSet {
+        let zero_based = |set: &PitchClassSet| {
+            let normal_form = set.normal_form();
+            let first = normal_form.first().copied().unwrap_or(0);
+            normal_form
+                .iter()
+                .map(|pitch_class| (pitch_class + 12 - first) % 12)
+                .collect::<Vec<u8>>()
+        };
+        let original = zero_based(self);
+        let inverted = zero_based(&self.invert());
+        let compare = |form: &Vec<u8>| form.iter().rev().copied().collect::<Vec<u8>>();
+        let prime = if compare(&inverted) < compare(&original) {
+            inverted
+        } else {
+            original
+        };
+        Self::from_pitch_classes(&prime)
+    }
+
+    /// Number of times each interval class (1 to 6 semitones) occurs between the pitch
+    /// classes of the set.
+    pub fn interval_vector(&self) -> [u8; 6] {
+        let pitch_classes: Vec<u8> = self.pitch_classes().collect();
+        let mut vector = [0; 6];
+        for (index, first) in pitch_classes.iter().enumerate() {
+            for second in &pitch_classes[index + 1..] {
+                let interval = second - first;
+                let interval_class = interval.min(12 - interval);
+                vector[interval_class as usize - 1] += 1;
+            }
+        }
+        vector
+    }
+
+    /// Forte number of the set class, for example `3-11` for major and minor triads.
+    ///
+    /// Returns `None` for sets with less than 3 or more than 9 pitch classes, Forte didn't
+    /// number those.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let diatonic = PitchClassSet::from_pitch_classes(&[0, 2, 4, 5, 7, 9, 11]);
+    /// assert_eq!(diatonic.forte_number(), Some("7-35"));
+    /// assert_eq!(diatonic.complement().forte_number(), Some("5-35"));
+    /// ```
+    pub fn forte_number(&self) -> Option<&'static str> {
+        let len = self.len();
+        if !(3..=9).contains(&len) {
+            return None;
+        }
+        if len <= 6 {
+            let prime = self.prime_form();
+            return FORTE_SET_CLASSES
+                .iter()
+                .find(|(_, pitch_classes)| {
+                    Self::from_pitch_classes(pitch_classes).prime_form() == prime
+                })
+                .map(|(name, _)| *name);
+        }
+        let complement_name = self.complement().forte_number()?;
+        FORTE_LARGE_SET_CLASSES
+            .iter()
+            .find(|(_, complement)| *complement == complement_name)
+            .map(|(name, _)| *name)
+    }
+}
+
+/// Forte names of the set classes with 7 to 9 pitch classes and the name of their complement.
+#[rustfmt::skip]
+const FORTE_LARGE_SET_CLASSES: [(&str, &str); 79] = [
+    ("9-1", "3-1"), ("9-2", "3-2"), ("9-3", "3-3"), ("9-4", "3-4"), ("9-5", "3-5"),
+    ("9-6", "3-6"), ("9-7", "3-7"), ("9-8", "3-8"), ("9-9", "3-9"), ("9-10", "3-10"),
+    ("9-11", "3-11"), ("9-12", "3-12"),
+
+    ("8-1", "4-1"), ("8-2", "4-2"), ("8-3", "4-3"), ("8-4", "4-4"), ("8-5", "4-5"),
+    ("8-6", "4-6"), ("8-7", "4-7"), ("8-8", "4-8"), ("8-9", "4-9"), ("8-10", "4-10"),
+    ("8-11", "4-11"), ("8-12", "4-12"), ("8-13", "4-13"), ("8-14", "4-14"),
+    ("8-Z15", "4-Z15"), ("8-16", "4-16"), ("8-17", "4-17"), ("8-18", "4-18"),
+    ("8-19", "4-19"), ("8-20", "4-20"), ("8-21", "4-21"), ("8-22", "4-22"),
+    ("8-23", "4-23"), ("8-24", "4-24"), ("8-25", "4-25"), ("8-26", "4-26"),
+    ("8-27", "4-27"), ("8-28", "4-28"), ("8-Z29", "4-Z29"),
+
+    ("7-1", "5-1"), ("7-2", "5-2"), ("7-3", "5-3"), ("7-4", "5-4"), ("7-5", "5-5"),
+    ("7-6", "5-6"), ("7-7", "5-7"), ("7-8", "5-8"), ("7-9", "5-9"), ("7-10", "5-10"),
+    ("7-11", "5-11"), ("7-Z12", "5-Z12"), ("7-13", "5-13"), ("7-14", "5-14"),
+    ("7-15", "5-15"), ("7-16", "5-16"), ("7-Z17", "5-Z17"), ("7-Z18", "5-Z18"),
+    ("7-19", "5-19"), ("7-20", "5-20"), ("7-21", "5-21"), ("7-22", "5-22"),
+    ("7-23", "5-23"), ("7-24", "5-24"), ("7-25", "5-25"), ("7-26", "5-26"),
+    ("7-27", "5-27"), ("7-28", "5-28"), ("7-29", "5-29"), ("7-30", "5-30"),
+    ("7-31", "5-31"), ("7-32", "5-32"), ("7-33", "5-33"), ("7-34", "5-34"),
+    ("7-35", "5-35"), ("7-Z36", "5-Z36"), ("7-Z37", "5-Z37"), ("7-Z38", "5-Z38"),
+];
+
+impl FromIterator<ChromaticTone> for PitchClassSet {
+    fn from_iter<I: IntoIterator<Item = ChromaticTone>>(iter: I) -> Self {
+        let mut set = PitchClassSet::EMPTY;
+        for tone in iter {
+            set.insert(tone);
+        }
+        set
+    }
+}
+
+/// Pitch classes in ascending order, for example `[0,4,7]`.
+impl Display for PitchClassSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pitch_classes: Vec<String> = self
+            .pitch_classes()
+            .map(|pitch_class| pitch_class.to_string())
+            .collect();
+        write!(f, "[{}]", pitch_classes.join(","))
+    }
+}
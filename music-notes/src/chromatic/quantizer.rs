@@ -0,0 +1,179 @@
+//! Quantizer
+//!
+//! Snaps notes that aren't part of a key to a note of the key. Used to lock played or
+//! transposed notes to a scale and to turn continuous values (pitch bend, controllers, a
+//! measured frequency) into melodic notes.
+use crate::{ChromaticKey, ChromaticNote, ChromaticReferencePitch, NoteStep, Pitch};
+
+/// Which note of the key to pick for a note that isn't part of the key.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QuantizeMode {
+    /// Nearest note of the key. When two notes are equally near the higher note is used.
+    #[default]
+    Nearest,
+    /// Nearest note of the key that is equal to or higher than the note.
+    Up,
+    /// Nearest note of the key that is equal to or lower than the note.
+    Down,
+    /// Nearest note of the key. When two notes are equally near the note closest to the root
+    /// of the key is used, the lower note when both are as close.
+    NearestTowardRoot,
+}
+
+/// Snap notes to the notes of a key.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let key = ChromaticKey::new(ChromaticTone::C, ChromaticScaleType::Major);
+/// let note = ChromaticNote::new(ChromaticTone::CSharp, 4);
+///
+/// let quantize = |mode| Quantizer::new(key, mode).quantize(note);
+/// assert_eq!(quantize(QuantizeMode::Nearest), ChromaticNote::new(ChromaticTone::D, 4));
+/// assert_eq!(quantize(QuantizeMode::Up), ChromaticNote::new(ChromaticTone::D, 4));
+/// assert_eq!(quantize(QuantizeMode::Down), ChromaticNote::new(ChromaticTone::C, 4));
+/// assert_eq!(
+///     quantize(QuantizeMode::NearestTowardRoot),
+///     ChromaticNote::new(ChromaticTone::C, 4)
+/// );
+///
+/// // Notes of the key are kept.
+/// let quantizer = Quantizer::new(key, QuantizeMode::Nearest);
+/// for note in NoteRange::PIANO.notes_in_key(&key) {
+///     assert_eq!(quantizer.quantize(note), note);
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quantizer {
+    pub key: ChromaticKey,
+    pub mode: QuantizeMode,
+}
+
+impl Quantizer {
+    pub fn new(key: ChromaticKey, mode: QuantizeMode) -> Quantizer {
+        Quantizer { key, mode }
+    }
+
+    /// Note of the key for the given note.
+    pub fn quantize(&self, note: ChromaticNote) -> ChromaticNote {
+        self.quantize_steps(i32::from(note) as f64).unwrap()
+    }
+
+    /// Note of the key for a fractional note index, where `48.5` is a quarter tone above C4.
+    ///
+    /// Steps outside the range of notes are clamped to the lowest or highest note of the key.
+    /// Returns `None` when steps isn't a number.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let key = ChromaticKey::new(ChromaticTone::C, ChromaticScaleType::Major);
+    /// let quantizer = Quantizer::new(key, QuantizeMode::Nearest);
+    /// let c4 = i32::from(ChromaticNote::new(ChromaticTone::C, 4)) as f64;
+    /// assert_eq!(
+    ///     quantizer.quantize_steps(c4 + 0.9),
+    ///     Some(ChromaticNote::new(ChromaticTone::C, 4))
+    /// );
+    /// assert_eq!(
+    ///     quantizer.quantize_steps(c4 + 1.1),
+    ///     Some(ChromaticNote::new(ChromaticTone::D, 4))
+    /// );
+    ///
+    /// assert_eq!(quantizer.quantize_steps(1e12), Some(ChromaticNote::max_value()));
+    /// assert_eq!(quantizer.quantize_steps(f64::INFINITY), Some(ChromaticNote::max_value()));
+    /// assert_eq!(quantizer.quantize_steps(f64::NEG_INFINITY), Some(ChromaticNote::min_value()));
+    /// assert_eq!(quantizer.quantize_steps(f64::NAN), None);
+    ///
+    /// // B127 isn't part of C# major and C#128 doesn't exist.
+    /// let key = ChromaticKey::new(ChromaticTone::CSharp, ChromaticScaleType::Major);
+    /// let quantizer = Quantizer::new(key, QuantizeMode::Up);
+    /// assert_eq!(
+    ///     quantizer.quantize(ChromaticNote::max_value()),
+    ///     ChromaticNote::new(ChromaticTone::ASharp, 127)
+    /// );
+    /// ```
+    pub fn quantize_steps(&self, steps: f64) -> Option<ChromaticNote> {
+        if steps.is_nan() {
+            return None;
+        }
+        let min = i32::from(ChromaticNote::min_value());
+        let max = i32::from(ChromaticNote::max_value());
+        let steps = steps.clamp(min as f64, max as f64);
+        let below = self.key_step_at_or_below(steps.floor() as NoteStep, min);
+        let above = self.key_step_at_or_above(steps.ceil() as NoteStep, max);
+        let step = match (below, above) {
+            (Some(below), Some(above)) => self.choose(steps, below, above),
+            (Some(step), None) | (None, Some(step)) => step,
+            (None, None) => steps.round() as NoteStep,
+        };
+        ChromaticNote::checked_from_index(step)
+    }
+
+    /// Note of the key for a frequency, using equal temperament and the given reference pitch.
+    ///
+    /// Returns `None` when the frequency isn't a positive number.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let key = ChromaticKey::new(ChromaticTone::C, ChromaticScaleType::Major);
+    /// let quantizer = Quantizer::new(key, QuantizeMode::Down);
+    /// let reference = ChromaticReferencePitch::default();
+    /// // Slightly above A#4.
+    /// let note = quantizer.quantize_pitch(&Pitch::new(470.0), &reference);
+    /// assert_eq!(note, Some(ChromaticNote::new(ChromaticTone::A, 4)));
+    /// ```
+    pub fn quantize_pitch(
+        &self,
+        pitch: &Pitch,
+        reference: &ChromaticReferencePitch,
+    ) -> Option<ChromaticNote> {
+        let (nearest, cents) = reference.nearest_note(pitch)?;
+        self.quantize_steps(i32::from(nearest) as f64 + cents / 100.0)
+    }
+
+    fn choose(&self, steps: f64, below: NoteStep, above: NoteStep) -> NoteStep {
+        let distance_below = steps - below as f64;
+        let distance_above = above as f64 - steps;
+        match self.mode {
+            QuantizeMode::Up => above,
+            QuantizeMode::Down => below,
+            QuantizeMode::Nearest if distance_below < distance_above => below,
+            QuantizeMode::Nearest => above,
+            QuantizeMode::NearestTowardRoot if distance_below < distance_above => below,
+            QuantizeMode::NearestTowardRoot if distance_above < distance_below => above,
+            QuantizeMode::NearestTowardRoot => {
+                if self.distance_to_root(above) < self.distance_to_root(below) {
+                    above
+                } else {
+                    below
+                }
+            }
+        }
+    }
+
+    /// Number of semitones to the nearest root above or below the step.
+    fn distance_to_root(&self, step: NoteStep) -> NoteStep {
+        let above_root = (step - u8::from(self.key.root) as NoteStep).rem_euclid(12);
+        above_root.min(12 - above_root)
+    }
+
+    fn key_step_at_or_below(&self, step: NoteStep, min: NoteStep) -> Option<NoteStep> {
+        (0..12)
+            .map(|offset| step - offset)
+            .take_while(|step| *step >= min)
+            .find(|step| self.key.contains(ChromaticNote::from(*step)))
+    }
+
+    fn key_step_at_or_above(&self, step: NoteStep, max: NoteStep) -> Option<NoteStep> {
+        (0..12)
+            .map(|offset| step + offset)
+            .take_while(|step| *step <= max)
+            .find(|step| self.key.contains(ChromaticNote::from(*step)))
+    }
+}
@@ -0,0 +1,355 @@
+//! Roman numerals
+//!
+//! Harmonic analysis writes chords as the scale degree of their root in the key, for example
+//! `ii7`, `V7/V` or `bVI`. Uppercase numerals are chords with a major third, lowercase numerals
+//! chords with a minor third. Chromatic roots are written with an accidental relative to the
+//! tone of the key.
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::{
+    Accidental, ChordSymbol, ChromaticChordType, ChromaticKey, ChromaticNote, ChromaticScaleType,
+    ChromaticTone, Interval, NoteStep, Octave, SpelledTone,
+};
+
+const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+/// Number of semitones above the root of the degrees of the major scale.
+const MAJOR_STEPS: [NoteStep; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// Degree of a scale, optionally altered with an accidental.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ScaleDegree {
+    pub accidental: Accidental,
+    /// Degree number, 1 is the tonic and 7 the leading tone.
+    pub number: u8,
+}
+
+impl ScaleDegree {
+    pub fn new(accidental: Accidental, number: u8) -> Self {
+        Self { accidental, number }
+    }
+
+    /// Unaltered tone of the degree in the key, spelled in the key.
+    fn reference_tone(&self, key: &ChromaticKey) -> SpelledTone {
+        let index = (self.number as usize + 6) % 7;
+        let tone = if key.scale_type.num_tones() == 7 {
+            key.note(0, index as NoteStep).tone
+        } else {
+            ChromaticTone::from(
+                (u8::from(key.root) as NoteStep + MAJOR_STEPS[index]).rem_euclid(12) as u8,
+            )
+        };
+        key.spell(tone)
+    }
+
+    /// Spelled tone of the degree in the key.
+    fn spelled_tone(&self, key: &ChromaticKey) -> SpelledTone {
+        let reference = self.reference_tone(key);
+        let tone =
+            u8::from(ChromaticTone::from(reference)) as NoteStep + self.accidental.semitones();
+        let tone = ChromaticTone::from(tone.rem_euclid(12) as u8);
+        SpelledTone::with_letter(tone, reference.letter).unwrap_or_else(|| SpelledTone::sharp(tone))
+    }
+
+    /// Degree of the given tone in the key, `None` when the tone is too far from the tone of
+    /// the degree to be written with an accidental.
+    fn of_tone(tone: ChromaticTone, key: &ChromaticKey) -> Option<ScaleDegree> {
+        let steps = (u8::from(tone) as NoteStep - u8::from(key.root) as NoteStep).rem_euclid(12);
+        let number = Interval::from_semitones(steps).number();
+        let reference =
+            ChromaticTone::from(ScaleDegree::new(Accidental::Natural, number).reference_tone(key));
+        let difference =
+            (u8::from(tone) as NoteStep - u8::from(reference) as NoteStep + 6).rem_euclid(12) - 6;
+        let accidental = Accidental::from_semitones(difference)?;
+        Some(ScaleDegree::new(accidental, number))
+    }
+}
+
+/// Chord written as a roman numeral relative to a key.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let key = ChromaticKey::new(ChromaticTone::C, ChromaticScaleType::Major);
+/// let numeral = RomanNumeral::analyze(ChromaticTone::D, ChromaticChordType::Seventh, &key);
+/// assert_eq!(numeral.unwrap().to_string(), "V7/V");
+/// let numeral = RomanNumeral::analyze(ChromaticTone::GSharp, ChromaticChordType::Major, &key);
+/// assert_eq!(numeral.unwrap().to_string(), "bVI");
+/// let numeral = RomanNumeral::analyze(ChromaticTone::B, ChromaticChordType::Diminished, &key);
+/// assert_eq!(numeral.unwrap().to_string(), "viio");
+///
+/// let numeral: RomanNumeral = "ii7".parse().unwrap();
+/// assert_eq!(numeral.chord_symbol(&key).to_string(), "Dm7");
+///
+/// // Every chord type survives a round trip through text.
+/// for chord_type in ChromaticChordType::ALL {
+///     let numeral = RomanNumeral::new(ScaleDegree::new(Accidental::Flat, 6), chord_type);
+///     assert_eq!(numeral.to_string().parse(), Ok(numeral));
+/// }
+/// assert_eq!("I6/9".parse::<RomanNumeral>().unwrap().to_string(), "I69");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RomanNumeral {
+    pub degree: ScaleDegree,
+    pub chord_type: ChromaticChordType,
+    /// Chord that is temporarily used as tonic, `V` in `V7/V`.
+    pub secondary: Option<Box<RomanNumeral>>,
+}
+
+impl RomanNumeral {
+    pub fn new(degree: ScaleDegree, chord_type: ChromaticChordType) -> Self {
+        Self {
+            degree,
+            chord_type,
+            secondary: None,
+        }
+    }
+
+    /// Analyze the chord with the given root and type in the key.
+    ///
+    /// Dominant chords that aren't part of the key and resolve to a major or minor chord of
+    /// the key are written as secondary dominants.
+    ///
+    /// Returns `None` when the root can't be written as an (altered) degree of the key, which
+    /// can happen in user defined scales.
+    pub fn analyze(
+        root: ChromaticTone,
+        chord_type: ChromaticChordType,
+        key: &ChromaticKey,
+    ) -> Option<RomanNumeral> {
+        if let Some(secondary) = Self::secondary_dominant(root, chord_type, key) {
+            return Some(secondary);
+        }
+        Some(RomanNumeral::new(
+            ScaleDegree::of_tone(root, key)?,
+            chord_type,
+        ))
+    }
+
+    fn secondary_dominant(
+        root: ChromaticTone,
+        chord_type: ChromaticChordType,
+        key: &ChromaticKey,
+    ) -> Option<RomanNumeral> {
+        let is_dominant = matches!(
+            chord_type,
+            ChromaticChordType::Major | ChromaticChordType::Seventh | ChromaticChordType::Ninth
+        );
+        let is_diatonic = chord_type.note_steps().iter().all(|step| {
+            key.contains_tone(ChromaticTone::from(
+                (u8::from(root) as NoteStep + step).rem_euclid(12) as u8,
+            ))
+        });
+        if !is_dominant || is_diatonic {
+            return None;
+        }
+        let target = ChromaticTone::from((u8::from(root) + 5) % 12);
+        let degree = key.degree_of_tone(target)?;
+        if degree == 0 {
+            return None;
+        }
+        let target_type = key.triad(degree)?;
+        if !matches!(
+            target_type,
+            ChromaticChordType::Major | ChromaticChordType::Minor
+        ) {
+            return None;
+        }
+        let mut numeral = RomanNumeral::new(ScaleDegree::new(Accidental::Natural, 5), chord_type);
+        numeral.secondary = Some(Box::new(RomanNumeral::new(
+            ScaleDegree::of_tone(target, key)?,
+            target_type,
+        )));
+        Some(numeral)
+    }
+
+    /// Key in which the degree of the numeral is interpreted. For secondary chords this is the
+    /// key of the chord that is used as tonic.
+    fn local_key(&self, key: &ChromaticKey) -> ChromaticKey {
+        match &self.secondary {
+            None => *key,
+            Some(secondary) => {
+                let tonic = ChromaticTone::from(secondary.root(key));
+                let scale_type = if secondary.is_major() {
+                    ChromaticScaleType::Major
+                } else {
+                    ChromaticScaleType::NaturalMinor
+                };
+                ChromaticKey::new(tonic, scale_type)
+            }
+        }
+    }
+
+    /// Spelled root of the chord in the key.
+    pub fn root(&self, key: &ChromaticKey) -> SpelledTone {
+        self.degree.spelled_tone(&self.local_key(key))
+    }
+
+    /// Chord of the numeral in the key.
+    pub fn chord_symbol(&self, key: &ChromaticKey) -> ChordSymbol {
+        ChordSymbol::new(self.root(key), self.chord_type)
+    }
+
+    /// Notes of the chord in the key with the root in the given octave.
+    pub fn notes(&self, key: &ChromaticKey, octave: Octave) -> Vec<ChromaticNote> {
+        self.chord_symbol(key).notes(octave)
+    }
+
+    /// Is the numeral written in uppercase.
+    fn is_major(&self) -> bool {
+        roman_suffix(self.chord_type).0
+    }
+}
+
+impl ChromaticKey {
+    /// Triad of stacked thirds of the key on the given degree (zero based).
+    ///
+    /// Returns `None` when the degree isn't part of the key or the triad isn't a major, minor,
+    /// diminished or augmented triad.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let key = ChromaticKey::new(ChromaticTone::A, ChromaticScaleType::HarmonicMinor);
+    /// assert_eq!(key.triad(4), Some(ChromaticChordType::Major));
+    /// assert_eq!(key.triad(2), Some(ChromaticChordType::Augmented));
+    /// ```
+    pub fn triad(&self, degree: usize) -> Option<ChromaticChordType> {
+        let root = self.note(4, degree as NoteStep);
+        let third = self.degrees_above(root, 2)?;
+        let fifth = self.degrees_above(root, 4)?;
+        match (
+            i32::from(third) - i32::from(root),
+            i32::from(fifth) - i32::from(root),
+        ) {
+            (4, 7) => Some(ChromaticChordType::Major),
+            (3, 7) => Some(ChromaticChordType::Minor),
+            (3, 6) => Some(ChromaticChordType::Diminished),
+            (4, 8) => Some(ChromaticChordType::Augmented),
+            _ => None,
+        }
+    }
+}
+
+/// Parse a progression of roman numerals separated by spaces, commas or dashes.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let key = ChromaticKey::new(ChromaticTone::F, ChromaticScaleType::Major);
+/// let chords: Vec<String> = parse_progression("ii7 - V7 - Imaj7")
+///     .unwrap()
+///     .iter()
+///     .map(|numeral| numeral.chord_symbol(&key).to_string())
+///     .collect();
+/// assert_eq!(chords, vec!["Gm7", "C7", "Fmaj7"]);
+/// ```
+pub fn parse_progression(text: &str) -> Result<Vec<RomanNumeral>, ParseRomanNumeralError> {
+    text.split([' ', ',', '-'])
+        .filter(|numeral| !numeral.is_empty())
+        .map(RomanNumeral::from_str)
+        .collect()
+}
+
+/// Case of the numeral (uppercase is `true`) and the text written after the numeral.
+fn roman_suffix(chord_type: ChromaticChordType) -> (bool, &'static str) {
+    match chord_type {
+        ChromaticChordType::Minor => (false, ""),
+        ChromaticChordType::Diminished => (false, "o"),
+        ChromaticChordType::DiminishedSeventh => (false, "o7"),
+        ChromaticChordType::HalfDiminished => (false, "ø7"),
+        ChromaticChordType::MinorSixth => (false, "6"),
+        ChromaticChordType::MinorSeventh => (false, "7"),
+        ChromaticChordType::MinorMajorSeventh => (false, "maj7"),
+        ChromaticChordType::MinorNinth => (false, "9"),
+        ChromaticChordType::MinorEleventh => (false, "11"),
+        ChromaticChordType::MinorThirteenth => (false, "13"),
+        ChromaticChordType::Augmented => (true, "+"),
+        ChromaticChordType::AugmentedSeventh => (true, "+7"),
+        chord_type => (true, chord_type.symbol()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRomanNumeralError {
+    /// The text doesn't start with a valid roman numeral.
+    InvalidNumeral(String),
+    /// The text after the numeral isn't a known chord type.
+    InvalidChordType(String),
+}
+
+impl Display for ParseRomanNumeralError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidNumeral(text) => write!(f, "`{text}` isn't a valid roman numeral"),
+            Self::InvalidChordType(text) => write!(f, "`{text}` isn't a valid chord type"),
+        }
+    }
+}
+
+impl Error for ParseRomanNumeralError {}
+
+impl FromStr for RomanNumeral {
+    type Err = ParseRomanNumeralError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The slash of `6/9` isn't a secondary chord.
+        let s = s.replace("6/9", "69");
+        let s = s.as_str();
+        let (numeral, secondary) = match s.split_once('/') {
+            Some((numeral, secondary)) => (numeral, Some(secondary.parse::<RomanNumeral>()?)),
+            None => (s, None),
+        };
+        let invalid_numeral = || ParseRomanNumeralError::InvalidNumeral(numeral.to_string());
+
+        let (accidental, rest) = match numeral.chars().next() {
+            Some(symbol @ ('b' | '♭')) => (Accidental::Flat, &numeral[symbol.len_utf8()..]),
+            Some(symbol @ ('#' | '♯')) => (Accidental::Sharp, &numeral[symbol.len_utf8()..]),
+            _ => (Accidental::Natural, numeral),
+        };
+        let end = rest
+            .find(|c: char| !matches!(c, 'I' | 'V' | 'i' | 'v'))
+            .unwrap_or(rest.len());
+        let (letters, suffix) = rest.split_at(end);
+        let is_major = letters.chars().all(|c| c.is_ascii_uppercase());
+        if !is_major && letters.chars().any(|c| c.is_ascii_uppercase()) {
+            return Err(invalid_numeral());
+        }
+        let number = NUMERALS
+            .iter()
+            .position(|numeral| numeral.eq_ignore_ascii_case(letters))
+            .ok_or_else(invalid_numeral)?
+            + 1;
+
+        let suffix = suffix.replace('°', "o");
+        let chord_type = ChromaticChordType::ALL
+            .iter()
+            .find(|chord_type| roman_suffix(**chord_type) == (is_major, suffix.as_str()))
+            .copied()
+            .ok_or_else(|| ParseRomanNumeralError::InvalidChordType(suffix.clone()))?;
+
+        let mut numeral = RomanNumeral::new(ScaleDegree::new(accidental, number as u8), chord_type);
+        numeral.secondary = secondary.map(Box::new);
+        Ok(numeral)
+    }
+}
+
+/// Roman numeral, for example `bVI`, `ii7` or `V7/V`.
+impl Display for RomanNumeral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (is_major, suffix) = roman_suffix(self.chord_type);
+        let numeral = NUMERALS[(self.degree.number as usize + 6) % 7];
+        let numeral = if is_major {
+            numeral.to_string()
+        } else {
+            numeral.to_lowercase()
+        };
+        write!(f, "{}{numeral}{suffix}", self.degree.accidental)?;
+        if let Some(secondary) = &self.secondary {
+            write!(f, "/{secondary}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt::Display;
+
+use crate::NoteStep;
+
+/// Scales and modes using tones of the chromatic scale.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChromaticScaleType {
+    Major,
+    NaturalMinor,
+    HarmonicMinor,
+    /// Ascending melodic minor.
+    MelodicMinor,
+    Ionian,
+    Dorian,
+    Phrygian,
+    Lydian,
+    Mixolydian,
+    Aeolian,
+    Locrian,
+    MajorPentatonic,
+    MinorPentatonic,
+    Blues,
+    WholeTone,
+    /// Octatonic scale starting with a half step.
+    OctatonicHalfWhole,
+    /// Octatonic scale starting with a whole step.
+    OctatonicWholeHalf,
+    /// User defined scale. Bit `n` is set when the tone `n` semitones above the root is part
+    /// of the scale. The root (bit 0) is always part of the scale.
+    Custom(u16),
+}
+
+impl ChromaticScaleType {
+    /// Number of semitones above the root of each tone of the scale, in ascending order.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    /// assert_eq!(ChromaticScaleType::Dorian.note_steps(), vec![0, 2, 3, 5, 7, 9, 10]);
+    /// assert_eq!(ChromaticScaleType::Custom(0b10010001).note_steps(), vec![0, 4, 7]);
+    /// ```
+    pub fn note_steps(&self) -> Vec<NoteStep> {
+        let mask = self.mask();
+        (0..12).filter(|step| mask & (1 << step) != 0).collect()
+    }
+
+    /// Bit mask of the tones in the scale. Bit `n` is set when the tone `n` semitones above
+    /// the root is part of the scale.
+    ///
+    /// Doesn't allocate, so it can be used in the audio thread.
+    pub fn mask(&self) -> u16 {
+        match self {
+            Self::Major | Self::Ionian => mask_of(&[0, 2, 4, 5, 7, 9, 11]),
+            Self::NaturalMinor | Self::Aeolian => mask_of(&[0, 2, 3, 5, 7, 8, 10]),
+            Self::HarmonicMinor => mask_of(&[0, 2, 3, 5, 7, 8, 11]),
+            Self::MelodicMinor => mask_of(&[0, 2, 3, 5, 7, 9, 11]),
+            Self::Dorian => mask_of(&[0, 2, 3, 5, 7, 9, 10]),
+            Self::Phrygian => mask_of(&[0, 1, 3, 5, 7, 8, 10]),
+            Self::Lydian => mask_of(&[0, 2, 4, 6, 7, 9, 11]),
+            Self::Mixolydian => mask_of(&[0, 2, 4, 5, 7, 9, 10]),
+            Self::Locrian => mask_of(&[0, 1, 3, 5, 6, 8, 10]),
+            Self::MajorPentatonic => mask_of(&[0, 2, 4, 7, 9]),
+            Self::MinorPentatonic => mask_of(&[0, 3, 5, 7, 10]),
+            Self::Blues => mask_of(&[0, 3, 5, 6, 7, 10]),
+            Self::WholeTone => mask_of(&[0, 2, 4, 6, 8, 10]),
+            Self::OctatonicHalfWhole => mask_of(&[0, 1, 3, 4, 6, 7, 9, 10]),
+            Self::OctatonicWholeHalf => mask_of(&[0, 2, 3, 5, 6, 8, 9, 11]),
+            Self::Custom(mask) => (mask & 0x0fff) | 1,
+        }
+    }
+
+    /// Create a user defined scale from the number of semitones above the root of each tone.
+    ///
+    /// Steps are reduced to a single octave.
+    pub fn from_note_steps(note_steps: &[NoteStep]) -> ChromaticScaleType {
+        let mask = note_steps
+            .iter()
+            .fold(1, |mask, step| mask | (1 << step.rem_euclid(12)));
+        Self::Custom(mask)
+    }
+
+    /// Number of tones in the scale.
+    pub fn num_tones(&self) -> usize {
+        self.mask().count_ones() as usize
+    }
+}
+
+const fn mask_of(note_steps: &[NoteStep]) -> u16 {
+    let mut mask = 0;
+    let mut index = 0;
+    while index < note_steps.len() {
+        mask |= 1 << note_steps[index];
+        index += 1;
+    }
+    mask
+}
+
+impl Display for ChromaticScaleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Major => "major",
+            Self::NaturalMinor => "minor",
+            Self::HarmonicMinor => "harmonic minor",
+            Self::MelodicMinor => "melodic minor",
+            Self::Ionian => "ionian",
+            Self::Dorian => "dorian",
+            Self::Phrygian => "phrygian",
+            Self::Lydian => "lydian",
+            Self::Mixolydian => "mixolydian",
+            Self::Aeolian => "aeolian",
+            Self::Locrian => "locrian",
+            Self::MajorPentatonic => "major pentatonic",
+            Self::MinorPentatonic => "minor pentatonic",
+            Self::Blues => "blues",
+            Self::WholeTone => "whole tone",
+            Self::OctatonicHalfWhole => "octatonic half-whole",
+            Self::OctatonicWholeHalf => "octatonic whole-half",
+            Self::Custom(mask) => return write!(f, "custom {mask:#014b}"),
+        };
+        write!(f, "{name}")
+    }
+}
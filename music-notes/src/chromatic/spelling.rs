@@ -0,0 +1,382 @@
+//! Spelling
+//!
+//! A chromatic tone can be written in multiple ways, D sharp and E flat are the same tone.
+//! Which spelling is correct depends on the context: in E flat major the tone is written as
+//! E flat, in B major as D sharp.
+use std::fmt::Display;
+
+use crate::{
+    ChromaticChordType, ChromaticKey, ChromaticScaleType, ChromaticTone, Interval, NoteStep,
+};
+
+/// Letter name of a tone.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Letter {
+    C,
+    D,
+    E,
+    F,
+    G,
+    A,
+    B,
+}
+
+const LETTERS: [Letter; 7] = [
+    Letter::C,
+    Letter::D,
+    Letter::E,
+    Letter::F,
+    Letter::G,
+    Letter::A,
+    Letter::B,
+];
+
+impl Letter {
+    /// Position of the letter starting from C.
+    pub fn index(&self) -> usize {
+        LETTERS.iter().position(|letter| letter == self).unwrap()
+    }
+
+    /// Letter at the given position starting from C. Wraps around after B.
+    pub fn from_index(index: NoteStep) -> Letter {
+        LETTERS[index.rem_euclid(7) as usize]
+    }
+
+    /// Number of semitones above C of the natural tone of the letter.
+    pub fn semitones(&self) -> NoteStep {
+        match self {
+            Letter::C => 0,
+            Letter::D => 2,
+            Letter::E => 4,
+            Letter::F => 5,
+            Letter::G => 7,
+            Letter::A => 9,
+            Letter::B => 11,
+        }
+    }
+}
+
+impl Display for Letter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Accidental {
+    DoubleFlat,
+    Flat,
+    Natural,
+    Sharp,
+    DoubleSharp,
+}
+
+impl Accidental {
+    /// Number of semitones the accidental alters the natural tone.
+    pub fn semitones(&self) -> NoteStep {
+        match self {
+            Accidental::DoubleFlat => -2,
+            Accidental::Flat => -1,
+            Accidental::Natural => 0,
+            Accidental::Sharp => 1,
+            Accidental::DoubleSharp => 2,
+        }
+    }
+
+    /// Accidental that alters the natural tone with the given number of semitones.
+    pub fn from_semitones(semitones: NoteStep) -> Option<Accidental> {
+        match semitones {
+            -2 => Some(Accidental::DoubleFlat),
+            -1 => Some(Accidental::Flat),
+            0 => Some(Accidental::Natural),
+            1 => Some(Accidental::Sharp),
+            2 => Some(Accidental::DoubleSharp),
+            _ => None,
+        }
+    }
+}
+
+/// Natural accidentals are not written.
+impl Display for Accidental {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Accidental::DoubleFlat => "bb",
+            Accidental::Flat => "b",
+            Accidental::Natural => "",
+            Accidental::Sharp => "#",
+            Accidental::DoubleSharp => "##",
+        };
+        write!(f, "{text}")
+    }
+}
+
+/// Tone written as a letter with an accidental.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let e_flat = SpelledTone::new(Letter::E, Accidental::Flat);
+/// assert_eq!(ChromaticTone::from(e_flat), ChromaticTone::DSharp);
+/// assert_eq!(e_flat.to_string(), "Eb");
+/// assert_eq!(SpelledTone::flat(ChromaticTone::DSharp), e_flat);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SpelledTone {
+    pub letter: Letter,
+    pub accidental: Accidental,
+}
+
+impl SpelledTone {
+    pub fn new(letter: Letter, accidental: Accidental) -> Self {
+        Self { letter, accidental }
+    }
+
+    /// Spell the tone with the given letter.
+    ///
+    /// Returns `None` when the tone can't be written with the letter and at most a double
+    /// accidental.
+    pub fn with_letter(tone: ChromaticTone, letter: Letter) -> Option<SpelledTone> {
+        let difference = u8::from(tone) as NoteStep - letter.semitones();
+        // Shortest distance, between -6 and 5 semitones.
+        let difference = (difference + 6).rem_euclid(12) - 6;
+        Accidental::from_semitones(difference).map(|accidental| Self::new(letter, accidental))
+    }
+
+    /// Spell the tone using a natural or a sharp.
+    pub fn sharp(tone: ChromaticTone) -> SpelledTone {
+        let letter = match tone {
+            ChromaticTone::C | ChromaticTone::CSharp => Letter::C,
+            ChromaticTone::D | ChromaticTone::DSharp => Letter::D,
+            ChromaticTone::E => Letter::E,
+            ChromaticTone::F | ChromaticTone::FSharp => Letter::F,
+            ChromaticTone::G | ChromaticTone::GSharp => Letter::G,
+            ChromaticTone::A | ChromaticTone::ASharp => Letter::A,
+            ChromaticTone::B => Letter::B,
+        };
+        Self::with_letter(tone, letter).unwrap()
+    }
+
+    /// Spell the tone using a natural or a flat.
+    pub fn flat(tone: ChromaticTone) -> SpelledTone {
+        let letter = match tone {
+            ChromaticTone::C => Letter::C,
+            ChromaticTone::CSharp | ChromaticTone::D => Letter::D,
+            ChromaticTone::DSharp | ChromaticTone::E => Letter::E,
+            ChromaticTone::F => Letter::F,
+            ChromaticTone::FSharp | ChromaticTone::G => Letter::G,
+            ChromaticTone::GSharp | ChromaticTone::A => Letter::A,
+            ChromaticTone::ASharp | ChromaticTone::B => Letter::B,
+        };
+        Self::with_letter(tone, letter).unwrap()
+    }
+
+    /// Spell the tone that is the given interval above this tone.
+    ///
+    /// The letter is determined by the interval number, the accidental by the size of the
+    /// interval.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let e_flat = SpelledTone::new(Letter::E, Accidental::Flat);
+    /// let g = SpelledTone::new(Letter::G, Accidental::Natural);
+    /// assert_eq!(e_flat.above(Interval::MAJOR_THIRD), Some(g));
+    /// ```
+    pub fn above(&self, interval: Interval) -> Option<SpelledTone> {
+        let letter =
+            Letter::from_index(self.letter.index() as NoteStep + interval.number() as NoteStep - 1);
+        let tone = ChromaticTone::from(*self);
+        let target = ChromaticTone::from(
+            (u8::from(tone) as NoteStep + interval.semitones()).rem_euclid(12) as u8,
+        );
+        Self::with_letter(target, letter)
+    }
+
+    /// Spell the tone that is the given interval above this tone, using a sharp spelling when
+    /// the letter of the interval would need more than a double accidental.
+    pub(crate) fn above_or_sharp(&self, interval: Interval) -> SpelledTone {
+        self.above(interval).unwrap_or_else(|| {
+            let tone = u8::from(ChromaticTone::from(*self)) as NoteStep + interval.semitones();
+            SpelledTone::sharp(ChromaticTone::from(tone.rem_euclid(12) as u8))
+        })
+    }
+}
+
+impl From<SpelledTone> for ChromaticTone {
+    fn from(value: SpelledTone) -> Self {
+        let semitones = value.letter.semitones() + value.accidental.semitones();
+        ChromaticTone::from(semitones.rem_euclid(12) as u8)
+    }
+}
+
+impl Display for SpelledTone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.letter, self.accidental)
+    }
+}
+
+impl ChromaticKey {
+    /// Spelling of the root of the key.
+    ///
+    /// The spelling that results in the fewest accidentals for the tones of the key is used.
+    /// When both spellings need the same number of accidentals the sharp spelling is used.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let key = ChromaticKey::new(ChromaticTone::DSharp, ChromaticScaleType::Major);
+    /// assert_eq!(key.spelled_root().to_string(), "Eb");
+    /// let key = ChromaticKey::new(ChromaticTone::GSharp, ChromaticScaleType::NaturalMinor);
+    /// assert_eq!(key.spelled_root().to_string(), "G#");
+    /// ```
+    pub fn spelled_root(&self) -> SpelledTone {
+        let candidates = [SpelledTone::sharp(self.root), SpelledTone::flat(self.root)];
+        candidates
+            .iter()
+            .min_by_key(|root| self.accidental_count(**root))
+            .copied()
+            .unwrap()
+    }
+
+    /// Spell the given tone in the context of this key.
+    ///
+    /// Tones of the key get consecutive letters starting from the root. Tones that aren't
+    /// part of the key use flats when the key signature has flats and sharps otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let key = ChromaticKey::new(ChromaticTone::DSharp, ChromaticScaleType::Major);
+    /// assert_eq!(key.spell(ChromaticTone::GSharp).to_string(), "Ab");
+    /// assert_eq!(key.spell(ChromaticTone::FSharp).to_string(), "Gb");
+    /// let key = ChromaticKey::new(ChromaticTone::FSharp, ChromaticScaleType::Major);
+    /// assert_eq!(key.spell(ChromaticTone::F).to_string(), "E#");
+    /// ```
+    pub fn spell(&self, tone: ChromaticTone) -> SpelledTone {
+        self.spell_with_root(self.spelled_root(), tone)
+    }
+
+    /// Number of sharps (positive) or flats (negative) in the key signature.
+    ///
+    /// Harmonic and melodic minor, the minor pentatonic and blues scales use the key signature
+    /// of the natural minor key, the major pentatonic scale the one of the major key.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let key = ChromaticKey::new(ChromaticTone::F, ChromaticScaleType::Major);
+    /// assert_eq!(key.key_signature(), -1);
+    /// let key = ChromaticKey::new(ChromaticTone::C, ChromaticScaleType::NaturalMinor);
+    /// assert_eq!(key.key_signature(), -3);
+    /// let key = ChromaticKey::new(ChromaticTone::E, ChromaticScaleType::Major);
+    /// assert_eq!(key.key_signature(), 4);
+    /// let key = ChromaticKey::new(ChromaticTone::A, ChromaticScaleType::HarmonicMinor);
+    /// assert_eq!(key.key_signature(), 0);
+    /// let key = ChromaticKey::new(ChromaticTone::D, ChromaticScaleType::MelodicMinor);
+    /// assert_eq!(key.key_signature(), -1);
+    /// let key = ChromaticKey::new(ChromaticTone::F, ChromaticScaleType::MajorPentatonic);
+    /// assert_eq!(key.key_signature(), -1);
+    ///
+    /// // Tones outside the key follow the key signature, not the root.
+    /// let key = ChromaticKey::new(ChromaticTone::F, ChromaticScaleType::Major);
+    /// assert_eq!(key.spell(ChromaticTone::DSharp).to_string(), "Eb");
+    /// let key = ChromaticKey::new(ChromaticTone::G, ChromaticScaleType::NaturalMinor);
+    /// assert_eq!(key.spell(ChromaticTone::CSharp).to_string(), "Db");
+    /// ```
+    pub fn key_signature(&self) -> NoteStep {
+        self.key_signature_with_root(self.spelled_root())
+    }
+
+    fn key_signature_with_root(&self, root: SpelledTone) -> NoteStep {
+        let scale_type = match self.scale_type {
+            ChromaticScaleType::HarmonicMinor
+            | ChromaticScaleType::MelodicMinor
+            | ChromaticScaleType::MinorPentatonic
+            | ChromaticScaleType::Blues => ChromaticScaleType::NaturalMinor,
+            ChromaticScaleType::MajorPentatonic => ChromaticScaleType::Major,
+            scale_type => scale_type,
+        };
+        let key = ChromaticKey::new(self.root, scale_type);
+        key.tones()
+            .filter_map(|tone| key.spell_in_key(root, tone))
+            .map(|spelled| spelled.accidental.semitones())
+            .sum()
+    }
+
+    fn spell_with_root(&self, root: SpelledTone, tone: ChromaticTone) -> SpelledTone {
+        self.spell_in_key(root, tone).unwrap_or_else(|| {
+            if self.key_signature_with_root(root) < 0 {
+                SpelledTone::flat(tone)
+            } else {
+                SpelledTone::sharp(tone)
+            }
+        })
+    }
+
+    /// Spelling of a tone of the key, `None` when the tone isn't part of the key or can't be
+    /// written with the letter of its degree.
+    fn spell_in_key(&self, root: SpelledTone, tone: ChromaticTone) -> Option<SpelledTone> {
+        let steps = (u8::from(tone) as NoteStep - u8::from(self.root) as NoteStep).rem_euclid(12);
+        if self.scale_type.num_tones() == 7 {
+            self.degree_of_tone(tone).and_then(|degree| {
+                let letter =
+                    Letter::from_index(root.letter.index() as NoteStep + degree as NoteStep);
+                SpelledTone::with_letter(tone, letter)
+            })
+        } else if self.contains_tone(tone) {
+            root.above(Interval::from_semitones(steps))
+        } else {
+            None
+        }
+    }
+
+    /// Tones of the key, starting at the root.
+    fn tones(&self) -> impl Iterator<Item = ChromaticTone> + '_ {
+        self.scale_type.note_steps().into_iter().map(|step| {
+            ChromaticTone::from((u8::from(self.root) as NoteStep + step).rem_euclid(12) as u8)
+        })
+    }
+
+    /// Number of accidentals needed to write the tones of the key with the given root.
+    fn accidental_count(&self, root: SpelledTone) -> NoteStep {
+        self.tones()
+            .map(|tone| {
+                self.spell_with_root(root, tone)
+                    .accidental
+                    .semitones()
+                    .abs()
+            })
+            .sum()
+    }
+}
+
+impl ChromaticChordType {
+    /// Spell the tones of the chord with the given root.
+    ///
+    /// Chord tones get the letter of their interval above the root. Tones that can't be
+    /// written that way (more than a double accidental) fall back to a sharp spelling.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let root = SpelledTone::new(Letter::A, Accidental::Flat);
+    /// let tones: Vec<String> = ChromaticChordType::MinorSeventh
+    ///     .spell(root)
+    ///     .iter()
+    ///     .map(|tone| tone.to_string())
+    ///     .collect();
+    /// assert_eq!(tones, vec!["Ab", "Cb", "Eb", "Gb"]);
+    /// ```
+    pub fn spell(&self, root: SpelledTone) -> Vec<SpelledTone> {
+        self.intervals()
+            .iter()
+            .map(|interval| root.above_or_sharp(*interval))
+            .collect()
+    }
+}
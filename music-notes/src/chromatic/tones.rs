@@ -1,6 +1,8 @@
+use std::fmt::Display;
+
 use crate::{tone::Tone, ChromaticScale};
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd)]
 pub enum ChromaticTone {
     C,
     CSharp,
@@ -62,3 +64,23 @@ impl From<ChromaticTone> for u8 {
         }
     }
 }
+
+impl Display for ChromaticTone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ChromaticTone::C => "C",
+            ChromaticTone::CSharp => "C#",
+            ChromaticTone::D => "D",
+            ChromaticTone::DSharp => "D#",
+            ChromaticTone::E => "E",
+            ChromaticTone::F => "F",
+            ChromaticTone::FSharp => "F#",
+            ChromaticTone::G => "G",
+            ChromaticTone::GSharp => "G#",
+            ChromaticTone::A => "A",
+            ChromaticTone::ASharp => "A#",
+            ChromaticTone::B => "B",
+        };
+        write!(f, "{name}")
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{ChromaticTone, RatioTuning};
+
+pub type ChromaticTuning = RatioTuning<ChromaticTone>;
+
+/// 5-limit just intonation ratios starting at the tonic.
+const JUST_INTONATION_RATIOS: [(u32, u32); 12] = [
+    (1, 1),
+    (16, 15),
+    (9, 8),
+    (6, 5),
+    (5, 4),
+    (4, 3),
+    (45, 32),
+    (3, 2),
+    (8, 5),
+    (5, 3),
+    (9, 5),
+    (15, 8),
+];
+
+/// Pythagorean ratios starting at the tonic. The chain of fifths runs from the minor second
+/// (-5) to the augmented fourth (6), so the wolf fifth is between F# and Db.
+const PYTHAGOREAN_RATIOS: [(u32, u32); 12] = [
+    (1, 1),
+    (256, 243),
+    (9, 8),
+    (32, 27),
+    (81, 64),
+    (4, 3),
+    (729, 512),
+    (3, 2),
+    (128, 81),
+    (27, 16),
+    (16, 9),
+    (243, 128),
+];
+
+/// Steps of a fifth from the tonic used to construct the tones of a meantone tuning. The
+/// chain runs from the minor third (-3) to the augmented fifth (8).
+const MEANTONE_FIFTHS: [i32; 12] = [0, 7, 2, -3, 4, -1, 6, 1, 8, 3, -2, 5];
+
+impl ChromaticTuning {
+    /// 5-limit just intonation with the given tonic.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let tuning = ChromaticTuning::just_intonation(ChromaticTone::D);
+    /// let ratio = tuning.ratio(
+    ///     ChromaticNote::new(ChromaticTone::D, 4),
+    ///     ChromaticNote::new(ChromaticTone::FSharp, 4),
+    /// );
+    /// assert!((ratio - 1.25).abs() < 1e-9);
+    /// ```
+    pub fn just_intonation(tonic: ChromaticTone) -> Self {
+        Self::from_fractions(tonic, &JUST_INTONATION_RATIOS)
+    }
+
+    /// Pythagorean tuning (stacked pure fifths) with the given tonic.
+    pub fn pythagorean(tonic: ChromaticTone) -> Self {
+        Self::from_fractions(tonic, &PYTHAGOREAN_RATIOS)
+    }
+
+    /// Quarter-comma meantone with the given tonic.
+    ///
+    /// Fifths are narrowed by a quarter of the syntonic comma so major thirds are pure.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let tuning = ChromaticTuning::quarter_comma_meantone(ChromaticTone::C);
+    /// let ratio = tuning.ratio(
+    ///     ChromaticNote::new(ChromaticTone::C, 4),
+    ///     ChromaticNote::new(ChromaticTone::E, 4),
+    /// );
+    /// assert!((ratio - 1.25).abs() < 1e-9);
+    /// ```
+    pub fn quarter_comma_meantone(tonic: ChromaticTone) -> Self {
+        let fifth = 5.0_f64.powf(0.25);
+        let ratios = MEANTONE_FIFTHS
+            .iter()
+            .map(|fifths| {
+                let ratio = fifth.powi(*fifths);
+                ratio / 2.0_f64.powf(ratio.log2().floor())
+            })
+            .collect();
+        Self::from_ratios(tonic, ratios).unwrap()
+    }
+
+    fn from_fractions(tonic: ChromaticTone, fractions: &[(u32, u32)]) -> Self {
+        let ratios = fractions
+            .iter()
+            .map(|(numerator, denominator)| *numerator as f64 / *denominator as f64)
+            .collect();
+        Self::from_ratios(tonic, ratios).unwrap()
+    }
+}
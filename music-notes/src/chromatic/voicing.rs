@@ -0,0 +1,152 @@
+//! Voicing
+//!
+//! A voicing decides which notes are played for a chord: which chord tone is in the bass and
+//! how the other tones are spread over the octaves.
+use crate::{ChromaticChordType, ChromaticNote, ChromaticTone, NoteStep};
+
+/// How the tones of a chord are spread over the octaves.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum VoicingStyle {
+    /// All chord tones stacked as close as possible.
+    #[default]
+    Close,
+    /// Every second note above the bass of the close voicing is moved up an octave.
+    Open,
+    /// The second highest note of the close voicing is moved down an octave.
+    Drop2,
+    /// The third highest note of the close voicing is moved down an octave.
+    Drop3,
+}
+
+/// Voicing of a chord.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let voicing = Voicing {
+///     style: VoicingStyle::Drop2,
+///     ..Voicing::default()
+/// };
+/// let c4 = ChromaticNote::new(ChromaticTone::C, 4);
+/// let notes: Vec<String> = voicing
+///     .notes(ChromaticChordType::MajorSeventh, c4)
+///     .unwrap()
+///     .iter()
+///     .map(|note| note.to_string())
+///     .collect();
+/// assert_eq!(notes, vec!["G3", "C4", "E4", "B4"]);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Voicing {
+    /// Inversion of the close voicing before the style is applied. See
+    /// [`ChromaticChordType::inversion`].
+    pub inversion: usize,
+    pub style: VoicingStyle,
+    /// Maximum number of semitones between the lowest and the highest note.
+    pub max_spread: Option<NoteStep>,
+}
+
+impl Voicing {
+    /// Notes of the chord with the given root position, in ascending order.
+    ///
+    /// Returns `None` when the chord doesn't have the inversion or when the notes are spread
+    /// wider than the maximum spread.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let voicing = Voicing {
+    ///     style: VoicingStyle::Open,
+    ///     max_spread: Some(12),
+    ///     ..Voicing::default()
+    /// };
+    /// let c4 = ChromaticNote::new(ChromaticTone::C, 4);
+    /// assert_eq!(voicing.notes(ChromaticChordType::Major, c4), None);
+    /// ```
+    pub fn notes(
+        &self,
+        chord_type: ChromaticChordType,
+        root_position: ChromaticNote,
+    ) -> Option<Vec<ChromaticNote>> {
+        if self.inversion >= chord_type.intervals().len() {
+            return None;
+        }
+        let mut notes: Vec<NoteStep> = chord_type
+            .inversion(root_position, self.inversion)
+            .iter()
+            .map(|note| i32::from(*note))
+            .collect();
+
+        let num_notes = notes.len();
+        match self.style {
+            VoicingStyle::Close => {}
+            VoicingStyle::Open => {
+                notes
+                    .iter_mut()
+                    .skip(1)
+                    .step_by(2)
+                    .for_each(|note| *note += 12);
+            }
+            VoicingStyle::Drop2 if num_notes >= 2 => notes[num_notes - 2] -= 12,
+            VoicingStyle::Drop3 if num_notes >= 3 => notes[num_notes - 3] -= 12,
+            VoicingStyle::Drop2 | VoicingStyle::Drop3 => {}
+        }
+        notes.sort();
+
+        let spread = notes[num_notes - 1] - notes[0];
+        if self
+            .max_spread
+            .is_some_and(|max_spread| spread > max_spread)
+        {
+            return None;
+        }
+        Some(notes.into_iter().map(ChromaticNote::from).collect())
+    }
+
+    /// Notes of the chord with the given root, moved by octaves to fit between `lowest` and
+    /// `highest` (inclusive).
+    ///
+    /// When the voicing fits in multiple octaves the one closest to the middle of the register
+    /// is used. Returns `None` when the voicing doesn't fit.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let voicing = Voicing {
+    ///     inversion: 1,
+    ///     ..Voicing::default()
+    /// };
+    /// let lowest = ChromaticNote::new(ChromaticTone::C, 2);
+    /// let highest = ChromaticNote::new(ChromaticTone::C, 4);
+    /// let notes: Vec<String> = voicing
+    ///     .notes_in(ChromaticChordType::Minor, ChromaticTone::A, lowest, highest)
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|note| note.to_string())
+    ///     .collect();
+    /// assert_eq!(notes, vec!["C3", "E3", "A3"]);
+    /// ```
+    pub fn notes_in(
+        &self,
+        chord_type: ChromaticChordType,
+        root: ChromaticTone,
+        lowest: ChromaticNote,
+        highest: ChromaticNote,
+    ) -> Option<Vec<ChromaticNote>> {
+        let notes = self.notes(chord_type, ChromaticNote::new(root, 0))?;
+        let low = i32::from(notes[0]);
+        let high = i32::from(notes[notes.len() - 1]);
+        let register_low = i32::from(lowest);
+        let register_high = i32::from(highest);
+
+        let first_octave = -(low - register_low).div_euclid(12);
+        let last_octave = (register_high - high).div_euclid(12);
+        let register_center = register_low + register_high;
+        let octaves = (first_octave..=last_octave)
+            .min_by_key(|octaves| (low + high + octaves * 24 - register_center).abs())?;
+        Some(notes.into_iter().map(|note| note + octaves * 12).collect())
+    }
+}
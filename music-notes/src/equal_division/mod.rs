@@ -0,0 +1,7 @@
+mod notes;
+mod scale;
+mod tones;
+
+pub use notes::*;
+pub use scale::*;
+pub use tones::*;
@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+use crate::{ChromaticNote, EqualDivisionTone, Note, ReferencePitch};
+
+/// Note of a scale that divides the octave in `N` equal steps.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let note = Tet24Note::new(Tet24Tone::new(23), 4);
+/// assert_eq!(note + 1, Tet24Note::new(Tet24Tone::new(0), 5));
+/// assert_eq!(i32::from(note + 1), 5 * 24);
+/// assert_eq!(Tet24Note::from(5 * 24 + 7).to_string(), "D#+5");
+/// ```
+pub type EqualDivisionNote<const N: usize> = Note<EqualDivisionTone<N>>;
+
+pub type Tet19Note = EqualDivisionNote<19>;
+pub type Tet24Note = EqualDivisionNote<24>;
+pub type Tet31Note = EqualDivisionNote<31>;
+
+impl<const N: usize> Display for EqualDivisionNote<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.tone, self.octave)
+    }
+}
+
+/// Default reference pitch is the first tone of octave 4 at the frequency of middle C in
+/// concert pitch (A4 = 440Hz).
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let reference = ReferencePitch::<Tet19Tone>::default();
+/// let pitch = reference.pitch(Tet19Note::new(Tet19Tone::new(0), 5));
+/// assert!((pitch.frequency - 523.2511306011972).abs() < 1e-9);
+/// ```
+impl<const N: usize> Default for ReferencePitch<EqualDivisionTone<N>> {
+    fn default() -> Self {
+        let middle_c = ReferencePitch::default().pitch(ChromaticNote::default());
+        Self::new(
+            EqualDivisionNote::new(EqualDivisionTone::new(0), 4),
+            middle_c,
+        )
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{EqualDivisionTone, Scale};
+
+/// Scale that divides the octave in `N` equal steps.
+///
+/// The chromatic scale is the most common equal division (12 steps), other divisions like
+/// 19, 24 (quarter tones) or 31 steps are used for microtonal music.
+#[derive(Default, PartialEq, Clone, Copy, Debug)]
+pub struct EqualDivisionScale<const N: usize> {}
+
+impl<const N: usize> Scale for EqualDivisionScale<N> {
+    type Tones = EqualDivisionTone<N>;
+
+    fn tones_per_octave(&self) -> usize {
+        N
+    }
+}
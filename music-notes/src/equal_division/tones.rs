@@ -0,0 +1,99 @@
+use std::fmt::Display;
+
+use crate::{ChromaticTone, EqualDivisionScale, Tone};
+
+/// Tone of a scale that divides the octave in `N` equal steps.
+///
+/// The tone is the number of steps above the first tone of the octave. `N` must be between 1
+/// and 256, other values don't compile.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// // Degrees wrap around at the number of steps.
+/// assert_eq!(Tet19Tone::from(20), Tet19Tone::new(1));
+/// assert_eq!(Tet24Tone::new(24).degree(), 0);
+/// ```
+///
+/// ```compile_fail
+/// use music_notes::*;
+///
+/// let tone = EqualDivisionTone::<0>::new(0);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+pub struct EqualDivisionTone<const N: usize> {
+    degree: u8,
+}
+
+impl<const N: usize> EqualDivisionTone<N> {
+    const VALID: () = assert!(N > 0 && N <= 256, "N must be between 1 and 256");
+
+    /// Create the tone that is `degree` steps above the first tone of the octave. Degrees of
+    /// `N` and above wrap around.
+    pub fn new(degree: u8) -> Self {
+        let () = Self::VALID;
+        Self {
+            degree: (degree as usize % N) as u8,
+        }
+    }
+
+    /// Number of steps above the first tone of the octave.
+    pub fn degree(&self) -> u8 {
+        self.degree
+    }
+}
+
+impl<const N: usize> Tone for EqualDivisionTone<N> {
+    type ScaleType = EqualDivisionScale<N>;
+
+    fn scale() -> Self::ScaleType {
+        EqualDivisionScale::default()
+    }
+}
+
+impl<const N: usize> From<u8> for EqualDivisionTone<N> {
+    fn from(value: u8) -> Self {
+        EqualDivisionTone::new(value)
+    }
+}
+
+impl<const N: usize> From<EqualDivisionTone<N>> for u8 {
+    fn from(value: EqualDivisionTone<N>) -> Self {
+        value.degree
+    }
+}
+
+/// Name of the tone.
+///
+/// When the octave is divided in a multiple of 12 steps the tone is named after the chromatic
+/// tone below it, followed by a `+` for each step above that chromatic tone. Other divisions
+/// are named by their degree and number of steps (`degree\N`).
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// assert_eq!(Tet24Tone::new(3).to_string(), "C#+");
+/// assert_eq!(Tet24Tone::new(18).to_string(), "A");
+/// assert_eq!(Tet19Tone::new(5).to_string(), "5\\19");
+/// ```
+impl<const N: usize> Display for EqualDivisionTone<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !N.is_multiple_of(12) {
+            return write!(f, "{}\\{N}", self.degree);
+        }
+        let steps_per_semitone = N / 12;
+        let degree = self.degree as usize;
+        let chromatic_tone = ChromaticTone::from((degree / steps_per_semitone) as u8);
+        let micro_steps = degree % steps_per_semitone;
+        write!(f, "{chromatic_tone}{}", "+".repeat(micro_steps))
+    }
+}
+
+/// Tone of the 19 tone equal temperament.
+pub type Tet19Tone = EqualDivisionTone<19>;
+/// Tone of the 24 tone equal temperament (quarter tones).
+pub type Tet24Tone = EqualDivisionTone<24>;
+/// Tone of the 31 tone equal temperament.
+pub type Tet31Tone = EqualDivisionTone<31>;
@@ -3,16 +3,28 @@
 //! ## History
 //! 
 
+mod arpeggio;
 mod chromatic;
+mod equal_division;
 mod note;
 mod octave;
 mod pitch;
+mod rhythm;
+mod scala;
 mod scale;
+#[cfg(feature = "serde")]
+mod serialization;
 mod tone;
+mod tuning;
 
+pub use arpeggio::*;
 pub use chromatic::*;
+pub use equal_division::*;
 pub use note::*;
 pub use octave::*;
 pub use pitch::*;
+pub use rhythm::*;
+pub use scala::*;
 pub use scale::*;
 pub use tone::*;
+pub use tuning::*;
@@ -1,4 +1,4 @@
-use std::ops::Add;
+use std::ops::{Add, Sub};
 
 use crate::{Octave, Scale, Tone};
 
@@ -29,15 +29,27 @@ where
     }
 }
 
+/// Note at the given index, where index 0 is the first tone of octave 0.
+///
+/// The conversion wraps: indices outside the range of [`Octave`] wrap around to the other end
+/// of the range, like [`Note::wrapping_add`]. Use [`Note::checked_from_index`] to detect them.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// assert_eq!(ChromaticNote::from(49), ChromaticNote::new(ChromaticTone::CSharp, 4));
+/// let above_max = i32::from(ChromaticNote::max_value()) + 1;
+/// assert_eq!(ChromaticNote::from(above_max), ChromaticNote::min_value());
+/// assert_eq!(ChromaticNote::checked_from_index(above_max), None);
+/// ```
 impl<T> From<i32> for Note<T>
 where
     T: Tone + From<u8>,
+    u8: From<T>,
 {
     fn from(value: i32) -> Self {
-        let scale = T::scale();
-        let octave = value / scale.tones_per_octave() as i32;
-        let note_index = value % scale.tones_per_octave() as i32;
-        Note::<T>::new(note_index as u8, octave as u8)
+        Note::<T>::wrapping_from_index(value as i64)
     }
 }
 
@@ -48,10 +60,113 @@ where
 {
     fn from(value: Note<T>) -> Self {
         let scale = T::scale();
-        (value.octave * scale.tones_per_octave() as u8 + u8::from(value.tone)) as i32
+        value.octave as i32 * scale.tones_per_octave() as i32 + u8::from(value.tone) as i32
     }
 }
 
+impl<T> Note<T>
+where
+    T: Tone + From<u8>,
+    u8: From<T>,
+{
+    /// Lowest note that can be stored, the first tone of the lowest octave.
+    pub fn min_value() -> Self {
+        Note::<T>::new(0, Octave::MIN)
+    }
+
+    /// Highest note that can be stored, the last tone of the highest octave.
+    pub fn max_value() -> Self {
+        Note::<T>::new(tones_per_octave::<T>() as u8 - 1, Octave::MAX)
+    }
+
+    /// Note at the given index, or `None` when the octave of the note doesn't fit in
+    /// [`Octave`].
+    pub fn checked_from_index(index: i32) -> Option<Self> {
+        let tones_per_octave = tones_per_octave::<T>();
+        let octave = Octave::try_from(index.div_euclid(tones_per_octave)).ok()?;
+        let tone_index = index.rem_euclid(tones_per_octave) as u8;
+        Some(Note::<T>::new(tone_index, octave))
+    }
+
+    /// Add steps to the note. Returns `None` when the result doesn't fit in [`Octave`].
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let c4 = ChromaticNote::new(ChromaticTone::C, 4);
+    /// assert_eq!(c4.checked_add(-49), Some(ChromaticNote::new(ChromaticTone::B, -1)));
+    /// assert_eq!(ChromaticNote::max_value().checked_add(1), None);
+    /// assert_eq!(ChromaticNote::min_value().checked_sub(1), None);
+    /// ```
+    pub fn checked_add(self, steps: NoteStep) -> Option<Self> {
+        Note::<T>::checked_from_index(i32::from(self).checked_add(steps)?)
+    }
+
+    /// Subtract steps from the note. Returns `None` when the result doesn't fit in [`Octave`].
+    pub fn checked_sub(self, steps: NoteStep) -> Option<Self> {
+        Note::<T>::checked_from_index(i32::from(self).checked_sub(steps)?)
+    }
+
+    /// Add steps to the note, stopping at [`Note::min_value`] and [`Note::max_value`].
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let c4 = ChromaticNote::new(ChromaticTone::C, 4);
+    /// assert_eq!(c4.saturating_add(7), ChromaticNote::new(ChromaticTone::G, 4));
+    /// assert_eq!(c4.saturating_add(NoteStep::MAX), ChromaticNote::max_value());
+    /// assert_eq!(c4.saturating_sub(NoteStep::MAX), ChromaticNote::min_value());
+    /// ```
+    pub fn saturating_add(self, steps: NoteStep) -> Self {
+        self.saturating_add_i64(steps as i64)
+    }
+
+    /// Subtract steps from the note, stopping at [`Note::min_value`] and [`Note::max_value`].
+    pub fn saturating_sub(self, steps: NoteStep) -> Self {
+        self.saturating_add_i64(-(steps as i64))
+    }
+
+    /// Add steps to the note, wrapping around from the highest to the lowest octave.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// assert_eq!(ChromaticNote::max_value().wrapping_add(1), ChromaticNote::min_value());
+    /// assert_eq!(ChromaticNote::min_value().wrapping_sub(1), ChromaticNote::max_value());
+    /// ```
+    pub fn wrapping_add(self, steps: NoteStep) -> Self {
+        Note::<T>::wrapping_from_index(i32::from(self) as i64 + steps as i64)
+    }
+
+    /// Subtract steps from the note, wrapping around from the lowest to the highest octave.
+    pub fn wrapping_sub(self, steps: NoteStep) -> Self {
+        Note::<T>::wrapping_from_index(i32::from(self) as i64 - steps as i64)
+    }
+
+    fn saturating_add_i64(self, steps: i64) -> Self {
+        let index = i32::from(self) as i64 + steps;
+        let min = i32::from(Note::<T>::min_value()) as i64;
+        let max = i32::from(Note::<T>::max_value()) as i64;
+        Note::<T>::wrapping_from_index(index.clamp(min, max))
+    }
+
+    fn wrapping_from_index(index: i64) -> Self {
+        let tones_per_octave = tones_per_octave::<T>() as i64;
+        let octave = index.div_euclid(tones_per_octave) as Octave;
+        let tone_index = index.rem_euclid(tones_per_octave) as u8;
+        Note::<T>::new(tone_index, octave)
+    }
+}
+
+fn tones_per_octave<T: Tone>() -> i32 {
+    T::scale().tones_per_octave() as i32
+}
+
+/// Add steps to a note, stopping at [`Note::min_value`] and [`Note::max_value`] like
+/// [`Note::saturating_add`]. Use [`Note::checked_add`] to detect notes that don't fit.
 impl<T> Add<NoteStep> for Note<T>
 where
     T: Tone + From<u8>,
@@ -59,8 +174,108 @@ where
 {
     type Output = Note<T>;
     fn add(self, rhs: NoteStep) -> Self::Output {
-        let mut value = i32::from(self);
-        value += rhs;
-        Note::<T>::from(value)
+        self.saturating_add(rhs)
+    }
+}
+
+/// Subtract steps from a note, stopping at [`Note::min_value`] and [`Note::max_value`] like
+/// [`Note::saturating_sub`]. Use [`Note::checked_sub`] to detect notes that don't fit.
+impl<T> Sub<NoteStep> for Note<T>
+where
+    T: Tone + From<u8>,
+    u8: From<T>,
+{
+    type Output = Note<T>;
+    fn sub(self, rhs: NoteStep) -> Self::Output {
+        self.saturating_sub(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChromaticNote, NoteStep};
+
+    fn note_range() -> (i32, i32) {
+        let min = i32::from(ChromaticNote::min_value());
+        let max = i32::from(ChromaticNote::max_value());
+        (min, max)
+    }
+
+    /// Checked arithmetic agrees with integer arithmetic over the whole range of notes.
+    #[test]
+    fn checked_arithmetic() {
+        let (min, max) = note_range();
+        assert_eq!((min, max), (-128 * 12, 127 * 12 + 11));
+        for index in min..=max {
+            let note = ChromaticNote::checked_from_index(index).unwrap();
+            assert_eq!(i32::from(note), index);
+            for steps in [0, 1, -1, 11, -11, 12, -12, max, min, max - min, min - max] {
+                let expected = ChromaticNote::checked_from_index(index + steps);
+                assert_eq!(expected.is_some(), (min..=max).contains(&(index + steps)));
+                assert_eq!(note.checked_add(steps), expected);
+                assert_eq!(note.checked_sub(-steps), expected);
+            }
+            assert_eq!(note.checked_add(NoteStep::MAX), None);
+            assert_eq!(note.checked_sub(NoteStep::MIN), None);
+        }
+        assert_eq!(ChromaticNote::checked_from_index(min - 1), None);
+        assert_eq!(ChromaticNote::checked_from_index(max + 1), None);
+    }
+
+    /// Saturating arithmetic clamps integer arithmetic over the whole range of notes.
+    #[test]
+    fn saturating_arithmetic() {
+        let (min, max) = note_range();
+        for index in min..=max {
+            let note = ChromaticNote::from(index);
+            for steps in [0, 1, -1, 12, -12, max, min, max - min, min - max] {
+                let expected = ChromaticNote::from((index + steps).clamp(min, max));
+                assert_eq!(note.saturating_add(steps), expected);
+                assert_eq!(note.saturating_sub(-steps), expected);
+                assert_eq!(note + steps, expected);
+                assert_eq!(note - -steps, expected);
+            }
+            assert_eq!(
+                note.saturating_add(NoteStep::MAX),
+                ChromaticNote::max_value()
+            );
+            assert_eq!(
+                note.saturating_add(NoteStep::MIN),
+                ChromaticNote::min_value()
+            );
+            assert_eq!(
+                note.saturating_sub(NoteStep::MIN),
+                ChromaticNote::max_value()
+            );
+        }
+    }
+
+    /// Wrapping arithmetic is integer arithmetic modulo the number of notes.
+    #[test]
+    fn wrapping_arithmetic() {
+        let (min, max) = note_range();
+        let (min, max) = (min as i64, max as i64);
+        let count = (max - min + 1) as NoteStep;
+        for index in min..=max {
+            let note = ChromaticNote::from(index as i32);
+            for steps in [
+                0,
+                1,
+                -1,
+                12,
+                -12,
+                count,
+                -count,
+                NoteStep::MAX,
+                NoteStep::MIN,
+            ] {
+                let wrapped = (index + steps as i64 - min).rem_euclid(count as i64) + min;
+                let expected = ChromaticNote::from(wrapped as i32);
+                assert_eq!(note.wrapping_add(steps), expected);
+                if steps != NoteStep::MIN {
+                    assert_eq!(note.wrapping_sub(-steps), expected);
+                }
+            }
+        }
     }
 }
@@ -6,6 +6,7 @@
 
 /// Octave
 /// 
-/// Octave is the number of the octave.
+/// Octave is the number of the octave. Octaves below octave 0 are negative; midi note 0 is in
+/// octave -1.
 /// TODO: OctaveId. I would want to reserve octave for an actual indication that includes a starting pitch.
-pub type Octave = u8;
+pub type Octave = i8;
@@ -1,3 +1,170 @@
+//! Pitch
+//!
+//! A pitch is the perceived frequency of a sound. Notes are mapped to pitches by using a
+//! reference pitch: a note with a known frequency. All other notes are derived from it by
+//! using a tuning. When no tuning is given the octave is divided in `tones_per_octave` equal
+//! steps.
+
+use crate::{EqualTemperament, Note, Tone, Tuning};
+
+/// Cents are a logarithmic unit to measure the distance between two pitches.
+///
+/// An octave is 1200 cents. In 12 tone equal temperament a semitone is 100 cents.
+pub type Cents = f64;
+
+/// Number of cents in an octave.
+pub const CENTS_PER_OCTAVE: Cents = 1200.0;
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pitch {
+    /// Frequency in Hz.
     pub frequency: f64,
 }
+
+impl Pitch {
+    pub fn new(frequency: f64) -> Self {
+        Self { frequency }
+    }
+
+    /// Distance in cents from `other` to this pitch.
+    ///
+    /// Result is positive when this pitch is higher than `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::Pitch;
+    ///
+    /// let cents = Pitch::new(880.0).cents_from(&Pitch::new(440.0));
+    /// assert!((cents - 1200.0).abs() < 1e-9);
+    /// ```
+    pub fn cents_from(&self, other: &Pitch) -> Cents {
+        CENTS_PER_OCTAVE * (self.frequency / other.frequency).log2()
+    }
+
+    /// Pitch that is the given number of cents higher (or lower when negative).
+    pub fn shifted_by_cents(&self, cents: Cents) -> Pitch {
+        Pitch::new(self.frequency * 2.0_f64.powf(cents / CENTS_PER_OCTAVE))
+    }
+}
+
+impl From<f64> for Pitch {
+    fn from(frequency: f64) -> Self {
+        Pitch::new(frequency)
+    }
+}
+
+/// Reference pitch used to convert between notes and pitches.
+///
+/// Concert pitch is in most cases A4 = 440Hz, but other references (A4 = 432Hz, or
+/// A4 = 415Hz for baroque music) are also in use.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ReferencePitch<T>
+where
+    T: Tone,
+{
+    /// Note that sounds at `pitch`.
+    pub note: Note<T>,
+    /// Pitch of `note`.
+    pub pitch: Pitch,
+}
+
+impl<T> ReferencePitch<T>
+where
+    T: Tone + Copy + From<u8>,
+    u8: From<T>,
+{
+    pub fn new<P>(note: Note<T>, pitch: P) -> Self
+    where
+        P: Into<Pitch>,
+    {
+        Self {
+            note,
+            pitch: pitch.into(),
+        }
+    }
+
+    /// Get the pitch of the given note using equal temperament.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let reference = ReferencePitch::default();
+    /// let pitch = reference.pitch(ChromaticNote::new(ChromaticTone::A, 5));
+    /// assert!((pitch.frequency - 880.0).abs() < 1e-9);
+    /// ```
+    pub fn pitch(&self, note: Note<T>) -> Pitch {
+        self.pitch_in(note, &EqualTemperament::default())
+    }
+
+    /// Get the pitch of the given note using the given tuning.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let reference = ReferencePitch::default();
+    /// let tuning = ChromaticTuning::just_intonation(ChromaticTone::A);
+    /// let pitch = reference.pitch_in(ChromaticNote::new(ChromaticTone::E, 5), &tuning);
+    /// assert!((pitch.frequency - 660.0).abs() < 1e-9);
+    /// ```
+    pub fn pitch_in<U>(&self, note: Note<T>, tuning: &U) -> Pitch
+    where
+        U: Tuning<T>,
+    {
+        Pitch::new(self.pitch.frequency * tuning.ratio(self.note, note))
+    }
+
+    /// Find the note that is the nearest to the given pitch using equal temperament.
+    ///
+    /// Returns the note and the distance in cents from the note to the given pitch, or `None`
+    /// when the frequency isn't a positive number, for example the 0Hz of silence.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let reference = ReferencePitch::default();
+    /// let (note, cents) = reference.nearest_note(&Pitch::new(445.0)).unwrap();
+    /// assert_eq!(note, ChromaticNote::new(ChromaticTone::A, 4));
+    /// assert!((cents - 19.56).abs() < 0.01);
+    ///
+    /// for frequency in [0.0, -440.0, f64::NAN, f64::INFINITY] {
+    ///     assert_eq!(reference.nearest_note(&Pitch::new(frequency)), None);
+    /// }
+    /// let (note, _cents) = reference.nearest_note(&Pitch::new(f64::MAX)).unwrap();
+    /// assert_eq!(note, ChromaticNote::max_value());
+    /// ```
+    pub fn nearest_note(&self, pitch: &Pitch) -> Option<(Note<T>, Cents)> {
+        self.nearest_note_in(pitch, &EqualTemperament::default())
+    }
+
+    /// Find the note that is the nearest to the given pitch using the given tuning.
+    ///
+    /// Returns the note and the distance in cents from the note to the given pitch, or `None`
+    /// when the frequency isn't a positive number. Pitches beyond the range of notes give the
+    /// lowest or highest note.
+    pub fn nearest_note_in<U>(&self, pitch: &Pitch, tuning: &U) -> Option<(Note<T>, Cents)>
+    where
+        U: Tuning<T>,
+    {
+        if !pitch.frequency.is_finite() || pitch.frequency <= 0.0 {
+            return None;
+        }
+        // Start with the nearest note when the period would be divided in equal steps, tunings
+        // only deviate a fraction of a step so the nearest note is this note or one of its
+        // neighbours.
+        let tones_per_period = tuning.tones_per_period() as f64;
+        let periods = (pitch.frequency / self.pitch.frequency).log(tuning.period());
+        let steps = (periods * tones_per_period).round();
+        let lowest = i32::from(Note::<T>::min_value());
+        let highest = i32::from(Note::<T>::max_value());
+        let index =
+            (i32::from(self.note) as f64 + steps).clamp(lowest as f64, highest as f64) as i32;
+        (index - 1..=index + 1)
+            .filter_map(Note::<T>::checked_from_index)
+            .map(|note| (note, pitch.cents_from(&self.pitch_in(note, tuning))))
+            .min_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Mul},
+};
+
+use super::gcd;
+use crate::Ticks;
+
+/// Length of a note as a fraction of a whole note.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let dotted_quarter = Duration::QUARTER.dotted(1).unwrap();
+/// assert_eq!(dotted_quarter, Duration::new(3, 8));
+/// assert_eq!(dotted_quarter.ticks(480), 720);
+///
+/// let triplet_eighth = Duration::EIGHTH.tuplet(3, 2);
+/// assert_eq!(triplet_eighth * 3, Duration::QUARTER);
+/// assert_eq!(triplet_eighth.to_string(), "1/12");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Duration {
+    numerator: u32,
+    denominator: u32,
+}
+
+impl Duration {
+    pub const WHOLE: Duration = Duration::from_parts(1, 1);
+    pub const HALF: Duration = Duration::from_parts(1, 2);
+    pub const QUARTER: Duration = Duration::from_parts(1, 4);
+    pub const EIGHTH: Duration = Duration::from_parts(1, 8);
+    pub const SIXTEENTH: Duration = Duration::from_parts(1, 16);
+    pub const THIRTY_SECOND: Duration = Duration::from_parts(1, 32);
+    pub const SIXTY_FOURTH: Duration = Duration::from_parts(1, 64);
+    pub const HUNDRED_TWENTY_EIGHTH: Duration = Duration::from_parts(1, 128);
+
+    const fn from_parts(numerator: u32, denominator: u32) -> Duration {
+        Duration {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Create the duration of `numerator / denominator` whole notes.
+    ///
+    /// # Panics
+    ///
+    /// When `denominator` is zero.
+    pub fn new(numerator: u32, denominator: u32) -> Duration {
+        assert!(denominator != 0, "duration denominator must not be zero");
+        let divisor = gcd(numerator, denominator).max(1);
+        Duration::from_parts(numerator / divisor, denominator / divisor)
+    }
+
+    pub fn numerator(&self) -> u32 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> u32 {
+        self.denominator
+    }
+
+    /// Add the given number of dots. Each dot adds half of the previous addition, a dotted
+    /// quarter is a quarter plus an eighth.
+    ///
+    /// Returns `None` when the dotted duration can't be stored.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// assert_eq!(Duration::HALF.dotted(2), Some(Duration::new(7, 8)));
+    /// assert_eq!(Duration::QUARTER.dotted(31), None);
+    /// assert_eq!(Duration::QUARTER.dotted(40), None);
+    /// ```
+    pub fn dotted(&self, dots: u32) -> Option<Duration> {
+        let power = 1_u32
+            .checked_shl(dots)
+            .filter(|power| power.leading_zeros() > 0)?;
+        Some(Duration::new(
+            self.numerator.checked_mul(2 * power - 1)?,
+            self.denominator.checked_mul(power)?,
+        ))
+    }
+
+    /// Duration inside a tuplet that plays `actual` notes in the time of `normal` notes. A
+    /// triplet is `tuplet(3, 2)`.
+    pub fn tuplet(&self, actual: u32, normal: u32) -> Duration {
+        Duration::new(self.numerator * normal, self.denominator * actual)
+    }
+
+    /// Length of the duration in ticks at the given number of ticks per quarter note. The
+    /// result is rounded down when the duration isn't a whole number of ticks.
+    pub fn ticks(&self, ppq: Ticks) -> Ticks {
+        ppq * 4 * self.numerator as Ticks / self.denominator as Ticks
+    }
+
+    /// Duration of the given number of ticks at the given number of ticks per quarter note.
+    pub fn from_ticks(ticks: Ticks, ppq: Ticks) -> Duration {
+        Duration::new(ticks as u32, ppq as u32 * 4)
+    }
+
+    /// Length of the duration in quarter notes.
+    pub fn quarters(&self) -> f64 {
+        4.0 * self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+    fn add(self, rhs: Duration) -> Self::Output {
+        Duration::new(
+            self.numerator * rhs.denominator + rhs.numerator * self.denominator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl Mul<u32> for Duration {
+    type Output = Duration;
+    fn mul(self, rhs: u32) -> Self::Output {
+        Duration::new(self.numerator * rhs, self.denominator)
+    }
+}
+
+/// Fraction of a whole note, for example `3/8`.
+impl Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
@@ -0,0 +1,27 @@
+//! Rhythm
+//!
+//! Musical time is measured in note durations (a quarter note, a dotted eighth) and bar/beat
+//! positions. Midi measures time in ticks, a number of pulses per quarter note (PPQ). Audio
+//! measures time in seconds. The tempo map converts between the three.
+
+mod duration;
+mod position;
+mod tempo;
+mod time_signature;
+
+pub use duration::*;
+pub use position::*;
+pub use tempo::*;
+pub use time_signature::*;
+
+/// Time in midi ticks.
+pub type Ticks = usize;
+
+/// Greatest common divisor, used to keep fractions small.
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
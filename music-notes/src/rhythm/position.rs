@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use crate::Ticks;
+
+/// Position in a song as bar, beat and tick within the beat.
+///
+/// All fields are zero based; the first beat of the song is bar 0, beat 0. The position is
+/// displayed one based, like sequencers do: `1.1.0`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    pub bar: u32,
+    pub beat: u32,
+    pub tick: Ticks,
+}
+
+impl Position {
+    pub fn new(bar: u32, beat: u32, tick: Ticks) -> Position {
+        Position { bar, beat, tick }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.bar + 1, self.beat + 1, self.tick)
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{Duration, Position, Ticks, TimeSignature};
+
+const MICROSECONDS_PER_MINUTE: f64 = 60_000_000.0;
+
+/// Speed of the music in quarter notes per minute.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tempo {
+    pub beats_per_minute: f64,
+}
+
+impl Default for Tempo {
+    fn default() -> Self {
+        Tempo::new(120.0)
+    }
+}
+
+impl Tempo {
+    pub fn new(beats_per_minute: f64) -> Tempo {
+        Tempo { beats_per_minute }
+    }
+
+    /// Create a tempo from the number of microseconds per quarter note, as used in standard
+    /// midi files.
+    pub fn from_microseconds_per_quarter(microseconds: u32) -> Tempo {
+        Tempo::new(MICROSECONDS_PER_MINUTE / microseconds as f64)
+    }
+
+    /// Number of microseconds per quarter note, as used in standard midi files.
+    pub fn microseconds_per_quarter(&self) -> u32 {
+        (MICROSECONDS_PER_MINUTE / self.beats_per_minute).round() as u32
+    }
+
+    pub fn seconds_per_quarter(&self) -> f64 {
+        60.0 / self.beats_per_minute
+    }
+
+    /// Length of the duration in seconds at this tempo.
+    pub fn seconds(&self, duration: Duration) -> f64 {
+        duration.quarters() * self.seconds_per_quarter()
+    }
+}
+
+/// Tempo and time signature changes of a song. Converts between ticks, bar/beat positions and
+/// seconds.
+///
+/// Time signature changes start a new bar.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let mut tempo_map = TempoMap::new(480, Tempo::new(120.0), TimeSignature::COMMON_TIME).unwrap();
+/// // Two bars of 4/4 at 120 bpm, then 3/4 at 60 bpm.
+/// tempo_map.set_tempo(3840, Tempo::new(60.0));
+/// tempo_map.set_time_signature(3840, TimeSignature::new(3, 4).unwrap());
+///
+/// assert_eq!(tempo_map.seconds(3840), 4.0);
+/// assert_eq!(tempo_map.seconds(3840 + 480), 5.0);
+/// assert_eq!(tempo_map.ticks_at_seconds(5.0), 4320);
+///
+/// let position = tempo_map.position(3840 + 1440 + 480 + 10);
+/// assert_eq!(position, Position::new(3, 1, 10));
+/// assert_eq!(position.to_string(), "4.2.10");
+/// assert_eq!(tempo_map.ticks(position), 3840 + 1440 + 480 + 10);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TempoMap {
+    ppq: Ticks,
+    /// Tempo changes ordered by tick, the first change is at tick 0.
+    tempos: Vec<(Ticks, Tempo)>,
+    /// Time signature changes ordered by tick, the first change is at tick 0.
+    time_signatures: Vec<(Ticks, TimeSignature)>,
+}
+
+impl TempoMap {
+    /// Create a tempo map with the given number of ticks per quarter note and the tempo and
+    /// time signature at the start of the song.
+    ///
+    /// Returns `None` when `ppq` is zero.
+    ///
+    /// At a low PPQ short beats (like the sixteenth notes of 7/16 at 1 PPQ) are shorter than a
+    /// tick. Positions count such beats as a single tick.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// assert!(TempoMap::new(0, Tempo::new(120.0), TimeSignature::COMMON_TIME).is_none());
+    ///
+    /// let seven_sixteen = TimeSignature::new(7, 16).unwrap();
+    /// let tempo_map = TempoMap::new(1, Tempo::new(120.0), seven_sixteen).unwrap();
+    /// assert_eq!(tempo_map.position(10), Position::new(10, 0, 0));
+    /// assert_eq!(tempo_map.ticks(Position::new(10, 0, 0)), 10);
+    /// ```
+    pub fn new(ppq: Ticks, tempo: Tempo, time_signature: TimeSignature) -> Option<TempoMap> {
+        if ppq == 0 {
+            return None;
+        }
+        Some(TempoMap {
+            ppq,
+            tempos: vec![(0, tempo)],
+            time_signatures: vec![(0, time_signature)],
+        })
+    }
+
+    /// Number of ticks per quarter note.
+    pub fn ppq(&self) -> Ticks {
+        self.ppq
+    }
+
+    /// Change the tempo at the given tick. Replaces an earlier change at the same tick.
+    pub fn set_tempo(&mut self, tick: Ticks, tempo: Tempo) {
+        insert_change(&mut self.tempos, tick, tempo);
+    }
+
+    /// Change the time signature at the given tick. A new bar starts at the tick.
+    pub fn set_time_signature(&mut self, tick: Ticks, time_signature: TimeSignature) {
+        insert_change(&mut self.time_signatures, tick, time_signature);
+    }
+
+    pub fn tempo_at(&self, tick: Ticks) -> Tempo {
+        change_at(&self.tempos, tick)
+    }
+
+    pub fn time_signature_at(&self, tick: Ticks) -> TimeSignature {
+        change_at(&self.time_signatures, tick)
+    }
+
+    /// Length of the duration in ticks.
+    pub fn duration_ticks(&self, duration: Duration) -> Ticks {
+        duration.ticks(self.ppq)
+    }
+
+    /// Time in seconds from the start of the song until the given tick.
+    pub fn seconds(&self, tick: Ticks) -> f64 {
+        let mut seconds = 0.0;
+        for (index, (start, tempo)) in self.tempos.iter().enumerate() {
+            if *start >= tick {
+                break;
+            }
+            let end = self
+                .tempos
+                .get(index + 1)
+                .map_or(tick, |(next, _)| (*next).min(tick));
+            seconds += self.quarters(end - start) * tempo.seconds_per_quarter();
+        }
+        seconds
+    }
+
+    /// Tick at the given time in seconds from the start of the song, rounded to the nearest
+    /// tick.
+    pub fn ticks_at_seconds(&self, seconds: f64) -> Ticks {
+        let mut remaining = seconds;
+        for (index, (start, tempo)) in self.tempos.iter().enumerate() {
+            let ticks_per_second = self.ppq as f64 / tempo.seconds_per_quarter();
+            if let Some((next, _)) = self.tempos.get(index + 1) {
+                let segment_seconds = (next - start) as f64 / ticks_per_second;
+                if remaining >= segment_seconds {
+                    remaining -= segment_seconds;
+                    continue;
+                }
+            }
+            return start + (remaining * ticks_per_second).round() as Ticks;
+        }
+        0
+    }
+
+    /// Bar, beat and tick of the given tick.
+    pub fn position(&self, tick: Ticks) -> Position {
+        let mut bar = 0;
+        for (index, (start, time_signature)) in self.time_signatures.iter().enumerate() {
+            let ticks_per_bar = self.ticks_per_bar(time_signature);
+            match self.time_signatures.get(index + 1) {
+                Some((next, _)) if *next <= tick => {
+                    bar += (next - start).div_ceil(ticks_per_bar) as u32;
+                }
+                _ => {
+                    let offset = tick - start;
+                    let ticks_per_beat = self.ticks_per_beat(time_signature);
+                    let in_bar = offset % ticks_per_bar;
+                    return Position::new(
+                        bar + (offset / ticks_per_bar) as u32,
+                        (in_bar / ticks_per_beat) as u32,
+                        in_bar % ticks_per_beat,
+                    );
+                }
+            }
+        }
+        Position::default()
+    }
+
+    /// Tick of the given bar, beat and tick.
+    pub fn ticks(&self, position: Position) -> Ticks {
+        let mut bar = 0;
+        for (index, (start, time_signature)) in self.time_signatures.iter().enumerate() {
+            let ticks_per_bar = self.ticks_per_bar(time_signature);
+            if let Some((next, _)) = self.time_signatures.get(index + 1) {
+                let bars = (next - start).div_ceil(ticks_per_bar) as u32;
+                if position.bar >= bar + bars {
+                    bar += bars;
+                    continue;
+                }
+            }
+            return start
+                + (position.bar - bar) as Ticks * ticks_per_bar
+                + position.beat as Ticks * self.ticks_per_beat(time_signature)
+                + position.tick;
+        }
+        0
+    }
+
+    /// Ticks per bar, at least one tick.
+    fn ticks_per_bar(&self, time_signature: &TimeSignature) -> Ticks {
+        time_signature.ticks_per_bar(self.ppq).max(1)
+    }
+
+    /// Ticks per beat, at least one tick.
+    fn ticks_per_beat(&self, time_signature: &TimeSignature) -> Ticks {
+        time_signature.ticks_per_beat(self.ppq).max(1)
+    }
+
+    fn quarters(&self, ticks: Ticks) -> f64 {
+        ticks as f64 / self.ppq as f64
+    }
+}
+
+fn insert_change<T>(changes: &mut Vec<(Ticks, T)>, tick: Ticks, value: T) {
+    match changes.binary_search_by_key(&tick, |(start, _)| *start) {
+        Ok(index) => changes[index].1 = value,
+        Err(index) => changes.insert(index, (tick, value)),
+    }
+}
+
+fn change_at<T: Copy>(changes: &[(Ticks, T)], tick: Ticks) -> T {
+    let index = changes.partition_point(|(start, _)| *start <= tick);
+    changes[index.saturating_sub(1)].1
+}
@@ -0,0 +1,154 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::{Duration, Ticks};
+
+/// Number of beats in a bar and the note value of a beat.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let six_eight: TimeSignature = "6/8".parse().unwrap();
+/// assert!(six_eight.is_compound());
+/// assert_eq!(six_eight.bar_duration(), Duration::HALF.dotted(1).unwrap());
+/// assert_eq!(six_eight.ticks_per_bar(480), 1440);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TimeSignature {
+    /// Number of beats in a bar.
+    pub numerator: u8,
+    /// Note value of a beat, 4 is a quarter note. Must be a power of two.
+    pub denominator: u8,
+}
+
+impl Default for TimeSignature {
+    fn default() -> Self {
+        TimeSignature::COMMON_TIME
+    }
+}
+
+impl TimeSignature {
+    pub const COMMON_TIME: TimeSignature = TimeSignature {
+        numerator: 4,
+        denominator: 4,
+    };
+    pub const CUT_TIME: TimeSignature = TimeSignature {
+        numerator: 2,
+        denominator: 2,
+    };
+
+    /// Create a time signature.
+    ///
+    /// Returns `None` when the numerator is zero or the denominator isn't a power of two.
+    pub fn new(numerator: u8, denominator: u8) -> Option<TimeSignature> {
+        if numerator == 0 || !denominator.is_power_of_two() {
+            return None;
+        }
+        Some(TimeSignature {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Duration of a single beat.
+    pub fn beat_duration(&self) -> Duration {
+        Duration::new(1, self.denominator as u32)
+    }
+
+    /// Duration of a complete bar.
+    pub fn bar_duration(&self) -> Duration {
+        self.beat_duration() * self.numerator as u32
+    }
+
+    /// Are the beats grouped in threes, like 6/8 or 12/8.
+    pub fn is_compound(&self) -> bool {
+        self.numerator > 3 && self.numerator.is_multiple_of(3)
+    }
+
+    pub fn ticks_per_beat(&self, ppq: Ticks) -> Ticks {
+        self.beat_duration().ticks(ppq)
+    }
+
+    pub fn ticks_per_bar(&self, ppq: Ticks) -> Ticks {
+        self.bar_duration().ticks(ppq)
+    }
+}
+
+impl Display for TimeSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseTimeSignatureError {
+    /// The text doesn't contain a `/` between the numerator and denominator.
+    MissingSlash,
+    /// The numerator or denominator isn't a number between 0 and 255.
+    InvalidNumber(String),
+    /// The numerator is zero or the denominator isn't a power of two.
+    InvalidTimeSignature { numerator: u8, denominator: u8 },
+}
+
+impl Display for ParseTimeSignatureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingSlash => write!(f, "time signature doesn't contain a `/`"),
+            Self::InvalidNumber(number) => write!(f, "`{number}` isn't a valid number"),
+            Self::InvalidTimeSignature {
+                numerator,
+                denominator,
+            } => write!(
+                f,
+                "{numerator}/{denominator} isn't a valid time signature, the numerator can't be \
+                 zero and the denominator must be a power of two"
+            ),
+        }
+    }
+}
+
+impl Error for ParseTimeSignatureError {}
+
+/// Read a time signature written as `numerator/denominator`.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// assert_eq!("7/8".parse(), Ok(TimeSignature::new(7, 8).unwrap()));
+/// assert_eq!("4".parse::<TimeSignature>(), Err(ParseTimeSignatureError::MissingSlash));
+/// assert_eq!(
+///     "3/x".parse::<TimeSignature>(),
+///     Err(ParseTimeSignatureError::InvalidNumber("x".to_string()))
+/// );
+/// assert_eq!(
+///     "3/6".parse::<TimeSignature>(),
+///     Err(ParseTimeSignatureError::InvalidTimeSignature {
+///         numerator: 3,
+///         denominator: 6
+///     })
+/// );
+/// ```
+impl FromStr for TimeSignature {
+    type Err = ParseTimeSignatureError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (numerator, denominator) = s
+            .trim()
+            .split_once('/')
+            .ok_or(ParseTimeSignatureError::MissingSlash)?;
+        let parse = |number: &str| {
+            number
+                .trim()
+                .parse::<u8>()
+                .map_err(|_| ParseTimeSignatureError::InvalidNumber(number.trim().to_string()))
+        };
+        let numerator = parse(numerator)?;
+        let denominator = parse(denominator)?;
+        TimeSignature::new(numerator, denominator).ok_or(
+            ParseTimeSignatureError::InvalidTimeSignature {
+                numerator,
+                denominator,
+            },
+        )
+    }
+}
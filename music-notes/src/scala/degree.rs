@@ -0,0 +1,101 @@
+use std::fmt::Display;
+
+use crate::{Note, Scale, Tone};
+
+/// Degree of a scala scale with `N` degrees per period.
+///
+/// The number of degrees of a [`crate::ScalaScale`] is only known after loading the file,
+/// notes need it at compile time. Notes of this tone are tuned by a scala scale with `N`
+/// pitches. `N` must be between 1 and 256.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let scale: ScalaScale = "Pentatonic
+/// 5
+/// 200.0
+/// 400.0
+/// 700.0
+/// 900.0
+/// 2/1
+/// ".parse().unwrap();
+///
+/// let reference = ReferencePitch::new(ScalaNote::<5>::new(0, 4), 261.6255653005986);
+/// let pitch = reference.pitch_in(ScalaNote::<5>::new(ScalaDegree::new(3), 4), &scale);
+/// let expected = 261.6255653005986 * 2.0_f64.powf(700.0 / 1200.0);
+/// assert!((pitch.frequency - expected).abs() < 1e-9);
+///
+/// // Degrees wrap around at the number of degrees per period.
+/// assert_eq!(ScalaDegree::<5>::new(7), ScalaDegree::new(2));
+/// assert_eq!(ScalaNote::<5>::from(12).to_string(), "2\\5 2");
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd)]
+pub struct ScalaDegree<const N: usize> {
+    degree: u8,
+}
+
+impl<const N: usize> ScalaDegree<N> {
+    const VALID: () = assert!(N > 0 && N <= 256, "N must be between 1 and 256");
+
+    /// Create the degree, wrapping around at `N` degrees.
+    pub fn new(degree: u8) -> Self {
+        let () = Self::VALID;
+        Self {
+            degree: (degree as usize % N) as u8,
+        }
+    }
+
+    /// Number of degrees above the first degree of the scale.
+    pub fn degree(&self) -> u8 {
+        self.degree
+    }
+}
+
+impl<const N: usize> Tone for ScalaDegree<N> {
+    type ScaleType = ScalaDegrees<N>;
+
+    fn scale() -> Self::ScaleType {
+        ScalaDegrees::default()
+    }
+}
+
+impl<const N: usize> From<u8> for ScalaDegree<N> {
+    fn from(value: u8) -> Self {
+        ScalaDegree::new(value)
+    }
+}
+
+impl<const N: usize> From<ScalaDegree<N>> for u8 {
+    fn from(value: ScalaDegree<N>) -> Self {
+        value.degree
+    }
+}
+
+/// Degrees are written as `degree\N`.
+impl<const N: usize> Display for ScalaDegree<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\\{N}", self.degree)
+    }
+}
+
+/// Degrees of a scala scale with `N` degrees per period, without their pitches.
+#[derive(Default, PartialEq, Clone, Copy, Debug)]
+pub struct ScalaDegrees<const N: usize> {}
+
+impl<const N: usize> Scale for ScalaDegrees<N> {
+    type Tones = ScalaDegree<N>;
+
+    fn tones_per_octave(&self) -> usize {
+        N
+    }
+}
+
+/// Note that is tuned by a scala scale with `N` degrees per period.
+pub type ScalaNote<const N: usize> = Note<ScalaDegree<N>>;
+
+impl<const N: usize> Display for ScalaNote<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.tone, self.octave)
+    }
+}
@@ -0,0 +1,52 @@
+use std::{error::Error, fmt::Display, io};
+
+#[derive(Debug)]
+pub enum ScalaError {
+    /// The file could not be read or written.
+    Io(io::Error),
+    /// The file ended before the expected value was found.
+    UnexpectedEnd { expected: &'static str },
+    /// A line doesn't contain the expected value.
+    InvalidLine {
+        line: usize,
+        expected: &'static str,
+        found: String,
+    },
+    /// The scale doesn't contain any pitches, therefore the period is unknown.
+    EmptyScale,
+    /// The text isn't a pitch in cents or a positive ratio.
+    InvalidPitch(String),
+}
+
+impl Display for ScalaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{error}"),
+            Self::UnexpectedEnd { expected } => {
+                write!(f, "unexpected end of file, expected {expected}")
+            }
+            Self::InvalidLine {
+                line,
+                expected,
+                found,
+            } => write!(f, "line {line}: expected {expected}, found `{found}`"),
+            Self::EmptyScale => write!(f, "scale doesn't contain any pitches"),
+            Self::InvalidPitch(pitch) => write!(f, "`{pitch}` isn't a pitch in cents or a ratio"),
+        }
+    }
+}
+
+impl Error for ScalaError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ScalaError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
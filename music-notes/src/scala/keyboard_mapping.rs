@@ -0,0 +1,228 @@
+use std::{fmt::Display, fs, path::Path, str::FromStr};
+
+use crate::{NoteStep, Pitch, ScalaScale};
+
+use super::{content_lines, first_word, ScalaError};
+
+/// Largest map size that is accepted. Missing entries are filled in, so the size limits the
+/// memory used by a mapping.
+const MAX_MAP_SIZE: usize = 1024;
+
+/// Keyboard mapping loaded from a scala keyboard mapping file (`.kbm`).
+///
+/// Maps midi keys to degrees of a scale and defines the frequency of a reference key.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let scale: ScalaScale = "Pentatonic
+/// 5
+/// 200.0
+/// 400.0
+/// 700.0
+/// 900.0
+/// 2/1
+/// ".parse().unwrap();
+///
+/// // Only map the white keys, A4 (midi key 69) is 440Hz.
+/// let mapping: KeyboardMapping = "! white keys only
+/// 12
+/// 0
+/// 127
+/// 60
+/// 69
+/// 440.0
+/// 5
+/// ! mapping
+/// 0
+/// x
+/// 1
+/// x
+/// 2
+/// x
+/// x
+/// 3
+/// x
+/// 4
+/// x
+/// x
+/// ".parse().unwrap();
+///
+/// assert!(mapping.pitch(&scale, 61).is_none());
+/// let pitch = mapping.pitch(&scale, 72).unwrap();
+/// let expected = 440.0 / 2.0_f64.powf(900.0 / 1200.0) * 2.0;
+/// assert!((pitch.frequency - expected).abs() < 1e-9);
+///
+/// let result = "18446744073709551615\n0\n127\n60\n69\n440.0\n5\n".parse::<KeyboardMapping>();
+/// assert!(matches!(result, Err(ScalaError::InvalidLine { line: 1, .. })));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyboardMapping {
+    /// First midi key that is mapped.
+    pub first_key: u8,
+    /// Last midi key that is mapped.
+    pub last_key: u8,
+    /// Midi key where the first entry of the mapping (and degree 0 of the scale) is mapped to.
+    pub middle_key: u8,
+    /// Midi key with a known frequency.
+    pub reference_key: u8,
+    /// Frequency of the `reference_key`.
+    pub reference_frequency: f64,
+    /// Degree of the scale used as formal octave. When the mapping repeats, degrees are
+    /// shifted by this amount.
+    pub octave_degree: usize,
+    /// Degree for each key of the mapping, `None` when the key isn't mapped.
+    ///
+    /// When empty, keys are mapped linear to the degrees of the scale.
+    pub mapping: Vec<Option<usize>>,
+}
+
+impl KeyboardMapping {
+    /// Linear mapping where each midi key is mapped to the next degree of the scale.
+    pub fn linear(middle_key: u8, reference_key: u8, reference_frequency: f64) -> Self {
+        Self {
+            first_key: 0,
+            last_key: 127,
+            middle_key,
+            reference_key,
+            reference_frequency,
+            octave_degree: 0,
+            mapping: Vec::new(),
+        }
+    }
+
+    /// Number of scale steps between degree 0 at the `middle_key` and the given key.
+    ///
+    /// Returns `None` when the key isn't mapped.
+    pub fn scale_steps(&self, key: u8) -> Option<NoteStep> {
+        if key < self.first_key || key > self.last_key {
+            return None;
+        }
+        let offset = key as NoteStep - self.middle_key as NoteStep;
+        if self.mapping.is_empty() {
+            return Some(offset);
+        }
+        let size = self.mapping.len() as NoteStep;
+        let repeats = offset.div_euclid(size);
+        let index = offset.rem_euclid(size) as usize;
+        self.mapping[index]
+            .map(|degree| repeats * self.octave_degree as NoteStep + degree as NoteStep)
+    }
+
+    /// Pitch of the given midi key using the given scale.
+    ///
+    /// Returns `None` when the key or the reference key isn't mapped.
+    pub fn pitch(&self, scale: &ScalaScale, key: u8) -> Option<Pitch> {
+        let steps = self.scale_steps(key)?;
+        let reference_steps = self.scale_steps(self.reference_key)?;
+        let ratio = scale.step_ratio(steps) / scale.step_ratio(reference_steps);
+        Some(Pitch::new(self.reference_frequency * ratio))
+    }
+
+    /// Load a keyboard mapping from a scala keyboard mapping file.
+    pub fn load<P>(path: P) -> Result<Self, ScalaError>
+    where
+        P: AsRef<Path>,
+    {
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Save the keyboard mapping as a scala keyboard mapping file.
+    pub fn save<P>(&self, path: P) -> Result<(), ScalaError>
+    where
+        P: AsRef<Path>,
+    {
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+}
+
+impl FromStr for KeyboardMapping {
+    type Err = ScalaError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let mut lines = content_lines(content).filter(|(_line, value)| !value.trim().is_empty());
+        let mut next_value = |expected: &'static str| -> Result<(usize, String), ScalaError> {
+            let (line, value) = lines.next().ok_or(ScalaError::UnexpectedEnd { expected })?;
+            Ok((line, first_word(value).to_string()))
+        };
+        fn parse<T: FromStr>(
+            (line, value): (usize, String),
+            expected: &'static str,
+        ) -> Result<T, ScalaError> {
+            value.parse().map_err(|_| ScalaError::InvalidLine {
+                line,
+                expected,
+                found: value,
+            })
+        }
+
+        let (line, value) = next_value("map size")?;
+        let size: usize = parse((line, value.clone()), "map size")?;
+        if size > MAX_MAP_SIZE {
+            return Err(ScalaError::InvalidLine {
+                line,
+                expected: "map size of at most 1024",
+                found: value,
+            });
+        }
+        let first_key = parse(next_value("first midi key")?, "first midi key")?;
+        let last_key = parse(next_value("last midi key")?, "last midi key")?;
+        let middle_key = parse(next_value("middle key")?, "middle key")?;
+        let reference_key = parse(next_value("reference key")?, "reference key")?;
+        let reference_frequency = parse(next_value("reference frequency")?, "reference frequency")?;
+        let octave_degree = parse(next_value("octave degree")?, "octave degree")?;
+
+        // Missing entries at the end of the mapping are not mapped.
+        let mut mapping = Vec::with_capacity(size);
+        while mapping.len() < size {
+            match next_value("mapping") {
+                Ok((_line, value)) if value == "x" => mapping.push(None),
+                Ok(value) => mapping.push(Some(parse(value, "scale degree or `x`")?)),
+                Err(_) => mapping.push(None),
+            }
+        }
+
+        Ok(Self {
+            first_key,
+            last_key,
+            middle_key,
+            reference_key,
+            reference_frequency,
+            octave_degree,
+            mapping,
+        })
+    }
+}
+
+/// Write the keyboard mapping in the scala keyboard mapping file format.
+impl Display for KeyboardMapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "! Size of map")?;
+        writeln!(f, "{}", self.mapping.len())?;
+        writeln!(f, "! First MIDI note number to retune")?;
+        writeln!(f, "{}", self.first_key)?;
+        writeln!(f, "! Last MIDI note number to retune")?;
+        writeln!(f, "{}", self.last_key)?;
+        writeln!(
+            f,
+            "! Middle note where the first entry of the mapping is mapped to"
+        )?;
+        writeln!(f, "{}", self.middle_key)?;
+        writeln!(f, "! Reference note for which frequency is given")?;
+        writeln!(f, "{}", self.reference_key)?;
+        writeln!(f, "! Frequency to tune the above note to")?;
+        writeln!(f, "{:.6}", self.reference_frequency)?;
+        writeln!(f, "! Scale degree to consider as formal octave")?;
+        writeln!(f, "{}", self.octave_degree)?;
+        writeln!(f, "! Mapping")?;
+        for degree in &self.mapping {
+            match degree {
+                Some(degree) => writeln!(f, "{degree}")?,
+                None => writeln!(f, "x")?,
+            }
+        }
+        Ok(())
+    }
+}
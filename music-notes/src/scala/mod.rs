@@ -0,0 +1,32 @@
+//! Scala tuning files
+//!
+//! [Scala](https://www.huygens-fokker.org/scala/) is the de facto standard to exchange
+//! tunings. A scale file (`.scl`) describes the pitches of a scale, a keyboard mapping file
+//! (`.kbm`) describes how midi keys are mapped to the degrees of a scale and which frequency
+//! is used as reference.
+//!
+//! Both formats are line based. Lines starting with `!` are comments.
+
+mod degree;
+mod error;
+mod keyboard_mapping;
+mod scale;
+
+pub use degree::*;
+pub use error::*;
+pub use keyboard_mapping::*;
+pub use scale::*;
+
+/// Iterate over the lines that aren't comments, together with their one based line number.
+fn content_lines(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim_end_matches('\r')))
+        .filter(|(_line_number, line)| !line.starts_with('!'))
+}
+
+/// Get the first word of a line. Scala ignores anything after the value.
+fn first_word(line: &str) -> &str {
+    line.split_whitespace().next().unwrap_or("")
+}
@@ -0,0 +1,271 @@
+use std::{fmt::Display, fs, path::Path, str::FromStr};
+
+use crate::{Cents, NoteStep, Scale, Tone, Tuning, CENTS_PER_OCTAVE};
+
+use super::{content_lines, first_word, ScalaError};
+
+/// Pitch of a degree in a scala scale, relative to the first degree of the scale.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ScalaPitch {
+    /// Pitch given in cents.
+    Cents(Cents),
+    /// Pitch given as a ratio (numerator, denominator).
+    Ratio(u64, u64),
+}
+
+impl ScalaPitch {
+    /// Frequency ratio of the pitch.
+    pub fn ratio(&self) -> f64 {
+        match self {
+            Self::Cents(cents) => 2.0_f64.powf(cents / CENTS_PER_OCTAVE),
+            Self::Ratio(numerator, denominator) => *numerator as f64 / *denominator as f64,
+        }
+    }
+
+    /// Distance of the pitch in cents.
+    pub fn cents(&self) -> Cents {
+        match self {
+            Self::Cents(cents) => *cents,
+            Self::Ratio(..) => CENTS_PER_OCTAVE * self.ratio().log2(),
+        }
+    }
+}
+
+/// Parse a pitch as written in a scale file.
+///
+/// Values containing a period are cents, other values are a ratio or a whole number.
+///
+/// # Example
+/// ```
+/// use music_notes::{ScalaError, ScalaPitch};
+///
+/// let parse = |value: &str| value.parse::<ScalaPitch>().unwrap();
+/// assert_eq!(parse("701.955"), ScalaPitch::Cents(701.955));
+/// assert_eq!(parse("3/2"), ScalaPitch::Ratio(3, 2));
+/// assert_eq!(parse("2"), ScalaPitch::Ratio(2, 1));
+/// assert!(matches!(
+///     "3/0".parse::<ScalaPitch>(),
+///     Err(ScalaError::InvalidPitch(pitch)) if pitch == "3/0"
+/// ));
+/// ```
+impl FromStr for ScalaPitch {
+    type Err = ScalaError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || ScalaError::InvalidPitch(value.to_string());
+        if value.contains('.') {
+            return value
+                .parse::<Cents>()
+                .ok()
+                .filter(|cents| cents.is_finite())
+                .map(Self::Cents)
+                .ok_or_else(invalid);
+        }
+        let (numerator, denominator) = value.split_once('/').unwrap_or((value, "1"));
+        let numerator = numerator.parse::<u64>().map_err(|_| invalid())?;
+        let denominator = denominator.parse::<u64>().map_err(|_| invalid())?;
+        if numerator == 0 || denominator == 0 {
+            return Err(invalid());
+        }
+        Ok(Self::Ratio(numerator, denominator))
+    }
+}
+
+impl Display for ScalaPitch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            // Cents must always contain a period to be recognized as cents.
+            Self::Cents(cents) => write!(f, "{cents:.5}"),
+            Self::Ratio(numerator, denominator) => write!(f, "{numerator}/{denominator}"),
+        }
+    }
+}
+
+/// Scale loaded from a scala scale file (`.scl`).
+///
+/// The first degree of the scale (1/1) is implicit. The last pitch of the scale is the period
+/// where the scale repeats itself, in most cases this is the octave (2/1).
+///
+/// The scale can be used as a tuning. Notes are mapped to consecutive degrees of the scale
+/// starting at the first tone of `T`. When the number of tones of `T` and the scale match
+/// (for example a 12 tone scale and `ChromaticTone`) each tone gets the pitch of its degree.
+///
+/// # Example
+/// ```
+/// use music_notes::*;
+///
+/// let scale: ScalaScale = "! meantone.scl
+/// !
+/// Quarter-comma meantone
+///  12
+/// !
+///  76.04900
+///  193.15686
+///  310.26471
+///  5/4
+///  503.42157
+///  579.47057
+///  696.57843
+///  25/16
+///  889.73529
+///  1006.84314
+///  1082.89214
+///  2/1
+/// ".parse().unwrap();
+/// assert_eq!(scale.description, "Quarter-comma meantone");
+/// assert_eq!(scale.tones_per_octave(), 12);
+///
+/// let reference = ChromaticReferencePitch::default();
+/// let pitch = reference.pitch_in(ChromaticNote::new(ChromaticTone::CSharp, 5), &scale);
+/// let expected = reference.pitch_in(ChromaticNote::new(ChromaticTone::A, 4), &scale);
+/// assert!((pitch.frequency / expected.frequency - 1.25).abs() < 1e-6);
+///
+/// // The number of notes can't be trusted.
+/// let result = "Broken\n18446744073709551615\n100.0\n".parse::<ScalaScale>();
+/// assert!(matches!(result, Err(ScalaError::UnexpectedEnd { .. })));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalaScale {
+    /// Description of the scale. Is the first line of the scale file.
+    pub description: String,
+    pitches: Vec<ScalaPitch>,
+}
+
+impl ScalaScale {
+    /// Create a scale from the given pitches. The implicit first degree (1/1) should not be
+    /// part of the pitches.
+    pub fn new<S>(description: S, pitches: Vec<ScalaPitch>) -> Result<Self, ScalaError>
+    where
+        S: Into<String>,
+    {
+        if pitches.is_empty() {
+            return Err(ScalaError::EmptyScale);
+        }
+        Ok(Self {
+            description: description.into(),
+            pitches,
+        })
+    }
+
+    /// Pitches of the scale, without the implicit first degree. The last pitch is the period.
+    pub fn pitches(&self) -> &[ScalaPitch] {
+        &self.pitches
+    }
+
+    /// Frequency ratio of the degree that is `steps` steps above the first degree.
+    ///
+    /// Steps can be negative or span multiple periods.
+    pub fn step_ratio(&self, steps: NoteStep) -> f64 {
+        let tones_per_period = self.pitches.len() as NoteStep;
+        let period = steps.div_euclid(tones_per_period);
+        let degree = steps.rem_euclid(tones_per_period) as usize;
+        let period_ratio = self.pitches.last().unwrap().ratio();
+        let degree_ratio = match degree {
+            0 => 1.0,
+            _ => self.pitches[degree - 1].ratio(),
+        };
+        period_ratio.powi(period) * degree_ratio
+    }
+
+    /// Load a scale from a scala scale file.
+    pub fn load<P>(path: P) -> Result<Self, ScalaError>
+    where
+        P: AsRef<Path>,
+    {
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Save the scale as a scala scale file.
+    pub fn save<P>(&self, path: P) -> Result<(), ScalaError>
+    where
+        P: AsRef<Path>,
+    {
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+}
+
+impl FromStr for ScalaScale {
+    type Err = ScalaError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let mut lines = content_lines(content);
+
+        let (_line, description) = lines.next().ok_or(ScalaError::UnexpectedEnd {
+            expected: "description",
+        })?;
+
+        let (line, count) = lines.next().ok_or(ScalaError::UnexpectedEnd {
+            expected: "number of notes",
+        })?;
+        let count = first_word(count)
+            .parse::<usize>()
+            .map_err(|_| ScalaError::InvalidLine {
+                line,
+                expected: "number of notes",
+                found: count.to_string(),
+            })?;
+
+        // The count isn't used to allocate, the file can't be trusted.
+        let mut pitches = Vec::new();
+        for _ in 0..count {
+            let (line, pitch) = lines
+                .next()
+                .ok_or(ScalaError::UnexpectedEnd { expected: "pitch" })?;
+            let pitch =
+                first_word(pitch)
+                    .parse::<ScalaPitch>()
+                    .map_err(|_| ScalaError::InvalidLine {
+                        line,
+                        expected: "pitch",
+                        found: pitch.to_string(),
+                    })?;
+            pitches.push(pitch);
+        }
+
+        Self::new(description.trim(), pitches)
+    }
+}
+
+/// Write the scale in the scala scale file format.
+impl Display for ScalaScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.description)?;
+        writeln!(f, " {}", self.pitches.len())?;
+        writeln!(f, "!")?;
+        for pitch in &self.pitches {
+            writeln!(f, " {pitch}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Scale for ScalaScale {
+    type Tones = ScalaPitch;
+
+    /// Number of degrees in a period of the scale.
+    fn tones_per_octave(&self) -> usize {
+        self.pitches.len()
+    }
+}
+
+impl<T> Tuning<T> for ScalaScale
+where
+    T: Tone,
+{
+    fn degree_ratio(&self, degree: usize) -> f64 {
+        ScalaScale::step_ratio(self, degree as NoteStep)
+    }
+
+    fn tones_per_period(&self) -> usize {
+        self.pitches.len()
+    }
+
+    fn period(&self) -> f64 {
+        self.pitches.last().unwrap().ratio()
+    }
+
+    fn step_ratio(&self, steps: NoteStep) -> f64 {
+        ScalaScale::step_ratio(self, steps)
+    }
+}
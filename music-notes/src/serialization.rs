@@ -0,0 +1,130 @@
+//! Serde support
+//!
+//! Notes, tones and chords are stored as the text musicians write, so presets and logs stay
+//! readable and can be edited by hand. Other types use the representation derived by serde.
+//!
+//! # Example
+//! ```
+//! use music_notes::*;
+//!
+//! let note = ChromaticNote::new(ChromaticTone::CSharp, 4);
+//! assert_eq!(serde_json::to_string(&note).unwrap(), "\"C#4\"");
+//! assert_eq!(serde_json::from_str::<ChromaticNote>("\"Db4\"").unwrap(), note);
+//!
+//! let key = ChromaticKey::new(ChromaticTone::DSharp, ChromaticScaleType::Major);
+//! let json = serde_json::to_string(&key).unwrap();
+//! assert_eq!(json, r#"{"root":"D#","scale_type":"Major"}"#);
+//! assert_eq!(serde_json::from_str::<ChromaticKey>(&json).unwrap(), key);
+//!
+//! let numeral = RomanNumeral::new(
+//!     ScaleDegree::new(Accidental::Natural, 1),
+//!     ChromaticChordType::SixNine,
+//! );
+//! let json = serde_json::to_string(&numeral).unwrap();
+//! assert_eq!(json, "\"I69\"");
+//! assert_eq!(serde_json::from_str::<RomanNumeral>(&json).unwrap(), numeral);
+//!
+//! let set = PitchClassSet::from_pitch_classes(&[0, 4, 7]);
+//! assert_eq!(serde_json::to_string(&set).unwrap(), "[0,4,7]");
+//!
+//! // Ranges are created with `NoteRange::new`, so swapped notes are put in order.
+//! let range: NoteRange = serde_json::from_str(r#"{"lowest":"C5","highest":"C4"}"#).unwrap();
+//! assert_eq!(range.lowest(), ChromaticNote::new(ChromaticTone::C, 4));
+//! assert_eq!(range.len(), 13);
+//! let json = serde_json::to_string(&NoteRange::PIANO).unwrap();
+//! assert_eq!(json, r#"{"lowest":"A0","highest":"C8"}"#);
+//! assert_eq!(serde_json::from_str::<NoteRange>(&json).unwrap(), NoteRange::PIANO);
+//! ```
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    parse_spelled_tone, ChordSymbol, ChromaticNote, ChromaticTone, NoteRange, PitchClassSet,
+    RomanNumeral, TimeSignature,
+};
+
+/// Serialize the type as its `Display` text and deserialize it with its `FromStr`
+/// implementation.
+macro_rules! serde_as_text {
+    ($($type:ty),*) => {$(
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer)?
+                    .parse()
+                    .map_err(D::Error::custom)
+            }
+        }
+    )*};
+}
+
+serde_as_text!(ChromaticNote, ChordSymbol, RomanNumeral);
+
+impl Serialize for ChromaticTone {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChromaticTone {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        match parse_spelled_tone(text.trim()).map_err(D::Error::custom)? {
+            (tone, "") => Ok(ChromaticTone::from(tone)),
+            (_, rest) => Err(D::Error::custom(format!(
+                "unexpected `{rest}` after tone `{text}`"
+            ))),
+        }
+    }
+}
+
+impl Serialize for TimeSignature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeSignature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Pitch class sets are stored as the list of their pitch classes, for example `[0, 4, 7]`.
+impl Serialize for PitchClassSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.pitch_classes())
+    }
+}
+
+impl<'de> Deserialize<'de> for PitchClassSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pitch_classes = Vec::<u8>::deserialize(deserializer)?;
+        if let Some(pitch_class) = pitch_classes.iter().find(|pitch_class| **pitch_class > 11) {
+            return Err(D::Error::custom(format!(
+                "{pitch_class} isn't a pitch class, expected 0 to 11"
+            )));
+        }
+        Ok(PitchClassSet::from_pitch_classes(&pitch_classes))
+    }
+}
+
+/// Note ranges are read through [`NoteRange::new`], so the lowest note can't be above the
+/// highest note.
+impl<'de> Deserialize<'de> for NoteRange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Notes {
+            lowest: ChromaticNote,
+            highest: ChromaticNote,
+        }
+        let notes = Notes::deserialize(deserializer)?;
+        Ok(NoteRange::new(notes.lowest, notes.highest))
+    }
+}
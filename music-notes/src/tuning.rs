@@ -0,0 +1,203 @@
+//! Tuning
+//!
+//! A tuning defines the frequency ratio of each tone of a scale relative to the tonic of the
+//! tuning. Equal temperament divides the octave in equal steps, other tunings (just
+//! intonation, pythagorean, meantone) use a table of ratios. These tables are relative to the
+//! tonic, moving the tonic moves the whole table.
+
+use std::{error::Error, fmt::Display};
+
+use crate::{Cents, Note, NoteStep, Scale, Tone, CENTS_PER_OCTAVE};
+
+pub trait Tuning<T>
+where
+    T: Tone,
+{
+    /// Frequency ratio of the tone that is `degree` steps above the tonic.
+    ///
+    /// `degree` is smaller than the number of tones per period. The ratio of degree 0 is 1.0.
+    fn degree_ratio(&self, degree: usize) -> f64;
+
+    /// Number of tones before the tuning repeats itself.
+    fn tones_per_period(&self) -> usize {
+        T::scale().tones_per_octave()
+    }
+
+    /// Frequency ratio of the interval the tuning repeats itself at.
+    ///
+    /// Most tunings repeat every octave.
+    fn period(&self) -> f64 {
+        2.0
+    }
+
+    /// Number of steps between the first tone of the scale and the tonic of the tuning.
+    fn tonic_offset(&self) -> NoteStep {
+        0
+    }
+
+    /// Frequency ratio of the tone that is `steps` steps above the tonic.
+    ///
+    /// Steps can be negative or span multiple octaves.
+    fn step_ratio(&self, steps: NoteStep) -> f64 {
+        let tones_per_period = self.tones_per_period() as NoteStep;
+        let period = steps.div_euclid(tones_per_period);
+        let degree = steps.rem_euclid(tones_per_period) as usize;
+        self.period().powi(period) * self.degree_ratio(degree)
+    }
+
+    /// Frequency ratio between two notes.
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let tuning = ChromaticTuning::just_intonation(ChromaticTone::C);
+    /// let ratio = tuning.ratio(
+    ///     ChromaticNote::new(ChromaticTone::C, 4),
+    ///     ChromaticNote::new(ChromaticTone::G, 4),
+    /// );
+    /// assert!((ratio - 1.5).abs() < 1e-9);
+    /// ```
+    fn ratio(&self, from: Note<T>, to: Note<T>) -> f64
+    where
+        u8: From<T>,
+    {
+        let tonic = self.tonic_offset();
+        self.step_ratio(i32::from(to) - tonic) / self.step_ratio(i32::from(from) - tonic)
+    }
+}
+
+/// Equal temperament divides the octave in steps with the same ratio.
+#[derive(Default, PartialEq, Clone, Copy, Debug)]
+pub struct EqualTemperament {}
+
+impl<T> Tuning<T> for EqualTemperament
+where
+    T: Tone,
+{
+    fn degree_ratio(&self, degree: usize) -> f64 {
+        let tones_per_octave = T::scale().tones_per_octave() as f64;
+        2.0_f64.powf(degree as f64 / tones_per_octave)
+    }
+}
+
+/// Tuning defined by a table of ratios, one for each degree above the tonic.
+#[derive(PartialEq, Clone, Debug)]
+pub struct RatioTuning<T>
+where
+    T: Tone,
+{
+    /// Tone where the first ratio of the table starts.
+    pub tonic: T,
+    ratios: Vec<f64>,
+}
+
+impl<T> RatioTuning<T>
+where
+    T: Tone,
+{
+    /// Create a tuning from the frequency ratios of each degree above the tonic.
+    ///
+    /// The table must contain a ratio for every tone of the scale, the ratio of the tonic must
+    /// be 1.0 and each ratio must be inside a single octave (`1.0 <= ratio < 2.0`).
+    ///
+    /// # Example
+    /// ```
+    /// use music_notes::*;
+    ///
+    /// let result = RatioTuning::from_ratios(ChromaticTone::C, vec![1.0, 1.5]);
+    /// assert_eq!(
+    ///     result,
+    ///     Err(TuningError::InvalidNumberOfDegrees {
+    ///         expected: 12,
+    ///         actual: 2
+    ///     })
+    /// );
+    ///
+    /// let mut ratios = vec![1.0; 12];
+    /// ratios[0] = 1.01;
+    /// assert_eq!(
+    ///     RatioTuning::from_ratios(ChromaticTone::C, ratios),
+    ///     Err(TuningError::InvalidTonicRatio(1.01))
+    /// );
+    /// ```
+    pub fn from_ratios(tonic: T, ratios: Vec<f64>) -> Result<Self, TuningError> {
+        let expected = T::scale().tones_per_octave();
+        if ratios.len() != expected {
+            return Err(TuningError::InvalidNumberOfDegrees {
+                expected,
+                actual: ratios.len(),
+            });
+        }
+        if ratios[0] != 1.0 {
+            return Err(TuningError::InvalidTonicRatio(ratios[0]));
+        }
+        if let Some((degree, ratio)) = ratios
+            .iter()
+            .enumerate()
+            .find(|(_degree, ratio)| !(1.0..2.0).contains(*ratio))
+        {
+            return Err(TuningError::RatioOutOfRange {
+                degree,
+                ratio: *ratio,
+            });
+        }
+        Ok(Self { tonic, ratios })
+    }
+
+    /// Create a tuning from the distance in cents of each degree above the tonic.
+    pub fn from_cents(tonic: T, cents: Vec<Cents>) -> Result<Self, TuningError> {
+        let ratios = cents
+            .iter()
+            .map(|cents| 2.0_f64.powf(cents / CENTS_PER_OCTAVE))
+            .collect();
+        Self::from_ratios(tonic, ratios)
+    }
+
+    /// Frequency ratios of each degree above the tonic.
+    pub fn ratios(&self) -> &[f64] {
+        &self.ratios
+    }
+}
+
+impl<T> Tuning<T> for RatioTuning<T>
+where
+    T: Tone + Copy,
+    u8: From<T>,
+{
+    fn degree_ratio(&self, degree: usize) -> f64 {
+        self.ratios[degree]
+    }
+
+    fn tonic_offset(&self) -> NoteStep {
+        u8::from(self.tonic) as NoteStep
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TuningError {
+    /// The number of degrees doesn't match the number of tones per octave of the scale.
+    InvalidNumberOfDegrees { expected: usize, actual: usize },
+    /// The ratio of a degree isn't inside a single octave.
+    RatioOutOfRange { degree: usize, ratio: f64 },
+    /// The ratio of the tonic isn't 1.0.
+    InvalidTonicRatio(f64),
+}
+
+impl Display for TuningError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidNumberOfDegrees { expected, actual } => {
+                write!(f, "expected {expected} degrees, got {actual}")
+            }
+            Self::RatioOutOfRange { degree, ratio } => {
+                write!(f, "ratio {ratio} of degree {degree} is outside an octave")
+            }
+            Self::InvalidTonicRatio(ratio) => {
+                write!(f, "ratio of the tonic must be 1.0, got {ratio}")
+            }
+        }
+    }
+}
+
+impl Error for TuningError {}